@@ -0,0 +1,75 @@
+-- Split sales into an order header (public.sales) and its line items (public.sales_line).
+BEGIN;
+
+ALTER TABLE IF EXISTS public.sales ADD COLUMN IF NOT EXISTS store_id integer;
+
+CREATE TABLE IF NOT EXISTS public.sales_line
+(
+    sales_line_id bigserial NOT NULL,
+    sales_id bigint NOT NULL,
+    product_id integer NOT NULL,
+    product_quantity integer NOT NULL,
+    total_price bigint NOT NULL,
+    CONSTRAINT sales_line_pkey PRIMARY KEY (sales_line_id)
+);
+
+CREATE INDEX IF NOT EXISTS sales_line_sales_id_idx ON public.sales_line (sales_id);
+
+-- Move existing single-product sales into one-line orders, then drop the per-product columns from the header.
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM information_schema.columns
+               WHERE table_schema = 'public' AND table_name = 'sales' AND column_name = 'product_id') THEN
+        INSERT INTO public.sales_line (sales_id, product_id, product_quantity, total_price)
+        SELECT sales_id,
+               product_id,
+               product_quantity,
+               COALESCE(NULLIF(regexp_replace(total_price::text, '[^0-9-]', '', 'g'), '')::bigint, 0)
+        FROM public.sales;
+
+        ALTER TABLE public.sales DROP CONSTRAINT IF EXISTS sales_product_fkey;
+        ALTER TABLE public.sales DROP COLUMN product_id;
+        ALTER TABLE public.sales DROP COLUMN product_quantity;
+    END IF;
+END $$;
+
+-- The API reads IDs and quantities other than a row's own ID as 32-bit integers, and the time of a sale with its time
+-- of day: invoice numbering, the sales filters and the tax periods all compare it as a time.
+ALTER TABLE IF EXISTS public.sales
+    ALTER COLUMN customer_id TYPE integer,
+    ALTER COLUMN sales_pair_id TYPE integer,
+    ALTER COLUMN sales_rep_id TYPE integer,
+    ALTER COLUMN store_id TYPE integer,
+    ALTER COLUMN sales_time TYPE timestamp without time zone;
+
+ALTER TABLE IF EXISTS public.sales_line
+    ALTER COLUMN product_id TYPE integer,
+    ALTER COLUMN product_quantity TYPE integer;
+
+ALTER TABLE IF EXISTS public.sales_line DROP CONSTRAINT IF EXISTS sales_line_sales_fkey;
+
+ALTER TABLE IF EXISTS public.sales_line
+    ADD CONSTRAINT sales_line_sales_fkey FOREIGN KEY (sales_id)
+    REFERENCES public.sales (sales_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.sales_line DROP CONSTRAINT IF EXISTS sales_line_product_fkey;
+
+ALTER TABLE IF EXISTS public.sales_line
+    ADD CONSTRAINT sales_line_product_fkey FOREIGN KEY (product_id)
+    REFERENCES public.product (product_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION
+    NOT VALID;
+
+ALTER TABLE IF EXISTS public.sales DROP CONSTRAINT IF EXISTS sales_store_fkey;
+
+ALTER TABLE IF EXISTS public.sales
+    ADD CONSTRAINT sales_store_fkey FOREIGN KEY (store_id)
+    REFERENCES public.store (store_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION
+    NOT VALID;
+
+END;
@@ -0,0 +1,30 @@
+-- Store money as integer cents and keep the server-computed price breakdown on each sales line.
+BEGIN;
+
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM information_schema.columns
+               WHERE table_schema = 'public' AND table_name = 'sales' AND column_name = 'total_price'
+                 AND data_type = 'character varying') THEN
+        ALTER TABLE public.sales
+            ALTER COLUMN total_price TYPE bigint
+            USING COALESCE(NULLIF(regexp_replace(total_price, '[^0-9-]', '', 'g'), '')::bigint, 0);
+    END IF;
+END $$;
+
+ALTER TABLE IF EXISTS public.sales_line ADD COLUMN IF NOT EXISTS unit_price bigint NOT NULL DEFAULT 0;
+ALTER TABLE IF EXISTS public.sales_line ADD COLUMN IF NOT EXISTS discount bigint NOT NULL DEFAULT 0;
+ALTER TABLE IF EXISTS public.sales_line ADD COLUMN IF NOT EXISTS discount_reason character varying COLLATE pg_catalog."default";
+
+-- Lines migrated from single-product sales only carry a total; derive their unit price from it.
+UPDATE public.sales_line
+SET unit_price = total_price / product_quantity
+WHERE unit_price = 0 AND product_quantity > 0;
+
+ALTER TABLE IF EXISTS public.sales_line DROP CONSTRAINT IF EXISTS sales_line_discount_reason_check;
+
+ALTER TABLE IF EXISTS public.sales_line
+    ADD CONSTRAINT sales_line_discount_reason_check CHECK (discount = 0 OR discount_reason IS NOT NULL)
+    NOT VALID;
+
+END;
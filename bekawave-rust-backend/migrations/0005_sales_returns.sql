@@ -0,0 +1,70 @@
+-- Goods returned by customers against an earlier sale.
+BEGIN;
+
+DO $$
+BEGIN
+    CREATE TYPE public.return_reason AS ENUM ('damaged', 'wrong_item', 'expired');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+DO $$
+BEGIN
+    CREATE TYPE public.refund_method AS ENUM ('cash', 'debt');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS public.sales_return
+(
+    return_id bigserial NOT NULL,
+    sales_id bigint NOT NULL,
+    customer_id integer NOT NULL,
+    store_id integer NOT NULL,
+    return_time timestamp without time zone NOT NULL,
+    refund_method public.refund_method NOT NULL,
+    cash_refund bigint NOT NULL DEFAULT 0,
+    debt_reduction bigint NOT NULL DEFAULT 0,
+    CONSTRAINT sales_return_pkey PRIMARY KEY (return_id),
+    CONSTRAINT sales_return_sales_fkey FOREIGN KEY (sales_id)
+        REFERENCES public.sales (sales_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE NO ACTION
+);
+
+CREATE INDEX IF NOT EXISTS sales_return_sales_id_idx ON public.sales_return (sales_id);
+
+CREATE TABLE IF NOT EXISTS public.sales_return_line
+(
+    return_line_id bigserial NOT NULL,
+    return_id bigint NOT NULL,
+    sales_line_id bigint NOT NULL,
+    product_id integer NOT NULL,
+    quantity integer NOT NULL,
+    reason public.return_reason NOT NULL,
+    restocked boolean NOT NULL DEFAULT false,
+    refund_amount bigint NOT NULL DEFAULT 0,
+    CONSTRAINT sales_return_line_pkey PRIMARY KEY (return_line_id),
+    CONSTRAINT sales_return_line_return_fkey FOREIGN KEY (return_id)
+        REFERENCES public.sales_return (return_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE CASCADE,
+    CONSTRAINT sales_return_line_sales_line_fkey FOREIGN KEY (sales_line_id)
+        REFERENCES public.sales_line (sales_line_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE NO ACTION,
+    CONSTRAINT sales_return_line_quantity_check CHECK (quantity > 0)
+);
+
+-- Customers, stores, products and quantities are read as 32-bit integers by the API, like on the sale returned.
+ALTER TABLE IF EXISTS public.sales_return
+    ALTER COLUMN customer_id TYPE integer,
+    ALTER COLUMN store_id TYPE integer;
+
+ALTER TABLE IF EXISTS public.sales_return_line
+    ALTER COLUMN product_id TYPE integer,
+    ALTER COLUMN quantity TYPE integer;
+
+CREATE INDEX IF NOT EXISTS sales_return_line_sales_line_id_idx ON public.sales_return_line (sales_line_id);
+
+END;
@@ -0,0 +1,14 @@
+-- Sales are voided instead of deleted; the row stays with who voided it, when and why.
+BEGIN;
+
+ALTER TABLE IF EXISTS public.sales ADD COLUMN IF NOT EXISTS voided_at timestamp without time zone;
+ALTER TABLE IF EXISTS public.sales ADD COLUMN IF NOT EXISTS voided_by character varying COLLATE pg_catalog."default";
+ALTER TABLE IF EXISTS public.sales ADD COLUMN IF NOT EXISTS void_reason character varying COLLATE pg_catalog."default";
+
+-- Debts of a voided or reworked sale are cancelled rather than deleted, keeping what was owed and why it no longer is.
+ALTER TABLE IF EXISTS public.debt ADD COLUMN IF NOT EXISTS cancelled_at timestamp without time zone;
+ALTER TABLE IF EXISTS public.debt ADD COLUMN IF NOT EXISTS cancel_reason character varying COLLATE pg_catalog."default";
+
+CREATE INDEX IF NOT EXISTS sales_voided_at_idx ON public.sales (voided_at) WHERE voided_at IS NOT NULL;
+
+END;
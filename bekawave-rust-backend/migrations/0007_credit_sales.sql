@@ -0,0 +1,41 @@
+-- Sales record how they were paid for; the unpaid part of a credit or partial sale is a debt linked to the sale.
+BEGIN;
+
+DO $$
+BEGIN
+    CREATE TYPE public.payment_mode AS ENUM ('cash', 'credit', 'partial');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+ALTER TABLE IF EXISTS public.sales ADD COLUMN IF NOT EXISTS payment_mode public.payment_mode NOT NULL DEFAULT 'cash';
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM information_schema.columns
+                   WHERE table_schema = 'public' AND table_name = 'sales' AND column_name = 'amount_paid') THEN
+        ALTER TABLE public.sales ADD COLUMN amount_paid bigint NOT NULL DEFAULT 0;
+        -- Sales recorded before payment modes existed are taken to have been paid in cash.
+        UPDATE public.sales SET amount_paid = total_price;
+    END IF;
+END $$;
+
+ALTER TABLE IF EXISTS public.debt ADD COLUMN IF NOT EXISTS sales_id bigint;
+
+-- The API reads a debt's customer as a 32-bit integer and its dates with their time of day.
+ALTER TABLE IF EXISTS public.debt
+    ALTER COLUMN customer_id TYPE integer,
+    ALTER COLUMN customer_date TYPE timestamp without time zone,
+    ALTER COLUMN paid_date TYPE timestamp without time zone;
+
+CREATE INDEX IF NOT EXISTS debt_sales_id_idx ON public.debt (sales_id);
+
+ALTER TABLE IF EXISTS public.debt DROP CONSTRAINT IF EXISTS debt_sales_fkey;
+
+ALTER TABLE IF EXISTS public.debt
+    ADD CONSTRAINT debt_sales_fkey FOREIGN KEY (sales_id)
+    REFERENCES public.sales (sales_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+END;
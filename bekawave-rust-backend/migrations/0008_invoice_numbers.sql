@@ -0,0 +1,54 @@
+-- Each store numbers its sales with a gap-free yearly invoice sequence, e.g. NRB-2026-000123.
+BEGIN;
+
+ALTER TABLE IF EXISTS public.store ADD COLUMN IF NOT EXISTS invoice_prefix character varying COLLATE pg_catalog."default";
+
+CREATE TABLE IF NOT EXISTS public.invoice_sequence
+(
+    store_id bigint NOT NULL,
+    year integer NOT NULL,
+    last_number bigint NOT NULL,
+    CONSTRAINT invoice_sequence_pkey PRIMARY KEY (store_id, year)
+);
+
+ALTER TABLE IF EXISTS public.invoice_sequence DROP CONSTRAINT IF EXISTS invoice_sequence_store_fkey;
+
+ALTER TABLE IF EXISTS public.invoice_sequence
+    ADD CONSTRAINT invoice_sequence_store_fkey FOREIGN KEY (store_id)
+    REFERENCES public.store (store_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM information_schema.columns
+                   WHERE table_schema = 'public' AND table_name = 'sales' AND column_name = 'invoice_number') THEN
+        ALTER TABLE public.sales ADD COLUMN invoice_number character varying COLLATE pg_catalog."default";
+
+        -- Number the sales recorded so far in the order they were made, per store and year.
+        WITH numbered AS (
+            SELECT s.sales_id,
+                   s.store_id,
+                   EXTRACT(YEAR FROM s.sales_time)::integer AS year,
+                   ROW_NUMBER() OVER (PARTITION BY s.store_id, EXTRACT(YEAR FROM s.sales_time)
+                                      ORDER BY s.sales_time, s.sales_id) AS number
+            FROM public.sales s
+            WHERE s.store_id IS NOT NULL
+        )
+        UPDATE public.sales s
+        SET invoice_number = COALESCE(st.invoice_prefix, 'S' || n.store_id) || '-' || n.year || '-' || LPAD(n.number::text, 6, '0')
+        FROM numbered n
+        JOIN public.store st ON st.store_id = n.store_id
+        WHERE s.sales_id = n.sales_id;
+
+        INSERT INTO public.invoice_sequence (store_id, year, last_number)
+        SELECT store_id, EXTRACT(YEAR FROM sales_time)::integer, COUNT(*)
+        FROM public.sales
+        WHERE invoice_number IS NOT NULL
+        GROUP BY store_id, EXTRACT(YEAR FROM sales_time)::integer;
+    END IF;
+END $$;
+
+CREATE UNIQUE INDEX IF NOT EXISTS sales_invoice_number_key ON public.sales (invoice_number);
+
+END;
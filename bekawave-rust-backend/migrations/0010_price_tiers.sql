@@ -0,0 +1,67 @@
+-- Customers buy at the price of their tier, or at a price set for them alone; products fall back to their base price.
+BEGIN;
+
+DO $$
+BEGIN
+    CREATE TYPE public.customer_tier AS ENUM ('retail', 'wholesale', 'distributor');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+ALTER TABLE IF EXISTS public.customer ADD COLUMN IF NOT EXISTS tier public.customer_tier NOT NULL DEFAULT 'retail';
+
+CREATE TABLE IF NOT EXISTS public.tier_price
+(
+    product_id integer NOT NULL,
+    tier public.customer_tier NOT NULL,
+    price bigint NOT NULL,
+    CONSTRAINT tier_price_pkey PRIMARY KEY (product_id, tier)
+);
+
+CREATE TABLE IF NOT EXISTS public.customer_price
+(
+    customer_id integer NOT NULL,
+    product_id integer NOT NULL,
+    price bigint NOT NULL,
+    CONSTRAINT customer_price_pkey PRIMARY KEY (customer_id, product_id)
+);
+
+-- Customers and products are read as 32-bit integers by the API.
+ALTER TABLE IF EXISTS public.tier_price
+    ALTER COLUMN product_id TYPE integer;
+
+ALTER TABLE IF EXISTS public.customer_price
+    ALTER COLUMN customer_id TYPE integer,
+    ALTER COLUMN product_id TYPE integer;
+
+ALTER TABLE IF EXISTS public.tier_price DROP CONSTRAINT IF EXISTS tier_price_price_check;
+ALTER TABLE IF EXISTS public.tier_price ADD CONSTRAINT tier_price_price_check CHECK (price >= 0);
+
+ALTER TABLE IF EXISTS public.tier_price DROP CONSTRAINT IF EXISTS tier_price_product_fkey;
+
+ALTER TABLE IF EXISTS public.tier_price
+    ADD CONSTRAINT tier_price_product_fkey FOREIGN KEY (product_id)
+    REFERENCES public.product (product_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.customer_price DROP CONSTRAINT IF EXISTS customer_price_price_check;
+ALTER TABLE IF EXISTS public.customer_price ADD CONSTRAINT customer_price_price_check CHECK (price >= 0);
+
+ALTER TABLE IF EXISTS public.customer_price DROP CONSTRAINT IF EXISTS customer_price_customer_fkey;
+
+ALTER TABLE IF EXISTS public.customer_price
+    ADD CONSTRAINT customer_price_customer_fkey FOREIGN KEY (customer_id)
+    REFERENCES public.customer (customer_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.customer_price DROP CONSTRAINT IF EXISTS customer_price_product_fkey;
+
+ALTER TABLE IF EXISTS public.customer_price
+    ADD CONSTRAINT customer_price_product_fkey FOREIGN KEY (product_id)
+    REFERENCES public.product (product_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+END;
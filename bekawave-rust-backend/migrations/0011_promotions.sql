@@ -0,0 +1,85 @@
+-- Promotions discount sales automatically while they run; every sales line keeps the discount each promotion gave it.
+BEGIN;
+
+DO $$
+BEGIN
+    CREATE TYPE public.promotion_kind AS ENUM ('percent_off', 'buy_x_get_y', 'order_amount_off');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS public.promotion
+(
+    promotion_id bigserial NOT NULL,
+    name character varying COLLATE pg_catalog."default" NOT NULL,
+    kind public.promotion_kind NOT NULL,
+    product_id integer,
+    customer_id integer,
+    customer_tier public.customer_tier,
+    starts_at timestamp without time zone NOT NULL,
+    ends_at timestamp without time zone NOT NULL,
+    percent_off integer NOT NULL DEFAULT 0,
+    buy_quantity integer NOT NULL DEFAULT 0,
+    free_quantity integer NOT NULL DEFAULT 0,
+    amount_off bigint NOT NULL DEFAULT 0,
+    min_order_total bigint NOT NULL DEFAULT 0,
+    stackable boolean NOT NULL DEFAULT false,
+    active boolean NOT NULL DEFAULT true,
+    CONSTRAINT promotion_pkey PRIMARY KEY (promotion_id)
+);
+
+CREATE INDEX IF NOT EXISTS promotion_window_idx ON public.promotion (starts_at, ends_at) WHERE active;
+
+ALTER TABLE IF EXISTS public.promotion DROP CONSTRAINT IF EXISTS promotion_window_check;
+ALTER TABLE IF EXISTS public.promotion ADD CONSTRAINT promotion_window_check CHECK (ends_at > starts_at);
+
+ALTER TABLE IF EXISTS public.promotion DROP CONSTRAINT IF EXISTS promotion_product_fkey;
+
+ALTER TABLE IF EXISTS public.promotion
+    ADD CONSTRAINT promotion_product_fkey FOREIGN KEY (product_id)
+    REFERENCES public.product (product_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.promotion DROP CONSTRAINT IF EXISTS promotion_customer_fkey;
+
+ALTER TABLE IF EXISTS public.promotion
+    ADD CONSTRAINT promotion_customer_fkey FOREIGN KEY (customer_id)
+    REFERENCES public.customer (customer_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.sales_line ADD COLUMN IF NOT EXISTS promotion_discount bigint NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS public.sales_line_promotion
+(
+    sales_line_id bigint NOT NULL,
+    promotion_id bigint NOT NULL,
+    discount bigint NOT NULL,
+    CONSTRAINT sales_line_promotion_pkey PRIMARY KEY (sales_line_id, promotion_id)
+);
+
+-- Products and customers are read as 32-bit integers by the API.
+ALTER TABLE IF EXISTS public.promotion
+    ALTER COLUMN product_id TYPE integer,
+    ALTER COLUMN customer_id TYPE integer;
+
+CREATE INDEX IF NOT EXISTS sales_line_promotion_promotion_id_idx ON public.sales_line_promotion (promotion_id);
+
+ALTER TABLE IF EXISTS public.sales_line_promotion DROP CONSTRAINT IF EXISTS sales_line_promotion_line_fkey;
+
+ALTER TABLE IF EXISTS public.sales_line_promotion
+    ADD CONSTRAINT sales_line_promotion_line_fkey FOREIGN KEY (sales_line_id)
+    REFERENCES public.sales_line (sales_line_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.sales_line_promotion DROP CONSTRAINT IF EXISTS sales_line_promotion_promotion_fkey;
+
+ALTER TABLE IF EXISTS public.sales_line_promotion
+    ADD CONSTRAINT sales_line_promotion_promotion_fkey FOREIGN KEY (promotion_id)
+    REFERENCES public.promotion (promotion_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+END;
@@ -0,0 +1,32 @@
+-- Products carry a VAT category and every sales line keeps the VAT it was charged.
+BEGIN;
+
+DO $$
+BEGIN
+    CREATE TYPE public.tax_category AS ENUM ('standard', 'zero_rated', 'exempt');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+ALTER TABLE IF EXISTS public.product ADD COLUMN IF NOT EXISTS tax_category public.tax_category NOT NULL DEFAULT 'standard';
+
+ALTER TABLE IF EXISTS public.sales_line ADD COLUMN IF NOT EXISTS tax_category public.tax_category NOT NULL DEFAULT 'standard';
+ALTER TABLE IF EXISTS public.sales_line ADD COLUMN IF NOT EXISTS tax_rate integer NOT NULL DEFAULT 0;
+ALTER TABLE IF EXISTS public.sales_line ADD COLUMN IF NOT EXISTS tax_amount bigint NOT NULL DEFAULT 0;
+
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM information_schema.columns
+                   WHERE table_schema = 'public' AND table_name = 'sales_line' AND column_name = 'net_price') THEN
+        ALTER TABLE public.sales_line ADD COLUMN net_price bigint NOT NULL DEFAULT 0;
+        -- Lines recorded before VAT was tracked carry no VAT: all of what was charged is net.
+        UPDATE public.sales_line SET net_price = total_price;
+    END IF;
+END $$;
+
+ALTER TABLE IF EXISTS public.sales_line DROP CONSTRAINT IF EXISTS sales_line_tax_check;
+
+ALTER TABLE IF EXISTS public.sales_line
+    ADD CONSTRAINT sales_line_tax_check CHECK (net_price + tax_amount = total_price);
+
+END;
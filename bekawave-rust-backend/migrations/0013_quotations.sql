@@ -0,0 +1,102 @@
+-- Quotations price goods for a customer ahead of a sale; an accepted quotation is converted into a sale once.
+BEGIN;
+
+DO $$
+BEGIN
+    CREATE TYPE public.quotation_status AS ENUM ('draft', 'sent', 'accepted', 'expired', 'rejected');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS public.quotation
+(
+    quotation_id bigserial NOT NULL,
+    customer_id integer NOT NULL,
+    store_id integer NOT NULL,
+    sales_rep_id integer,
+    sales_pair_id integer,
+    quoted_at timestamp without time zone NOT NULL,
+    valid_until timestamp without time zone NOT NULL,
+    status public.quotation_status NOT NULL DEFAULT 'draft',
+    total_price bigint NOT NULL DEFAULT 0,
+    sales_id bigint,
+    CONSTRAINT quotation_pkey PRIMARY KEY (quotation_id),
+    CONSTRAINT quotation_sales_id_key UNIQUE (sales_id)
+);
+
+CREATE INDEX IF NOT EXISTS quotation_customer_id_idx ON public.quotation (customer_id);
+CREATE INDEX IF NOT EXISTS quotation_open_idx ON public.quotation (valid_until) WHERE status IN ('draft', 'sent');
+
+ALTER TABLE IF EXISTS public.quotation DROP CONSTRAINT IF EXISTS quotation_customer_fkey;
+
+ALTER TABLE IF EXISTS public.quotation
+    ADD CONSTRAINT quotation_customer_fkey FOREIGN KEY (customer_id)
+    REFERENCES public.customer (customer_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.quotation DROP CONSTRAINT IF EXISTS quotation_store_fkey;
+
+ALTER TABLE IF EXISTS public.quotation
+    ADD CONSTRAINT quotation_store_fkey FOREIGN KEY (store_id)
+    REFERENCES public.store (store_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.quotation DROP CONSTRAINT IF EXISTS quotation_sales_fkey;
+
+ALTER TABLE IF EXISTS public.quotation
+    ADD CONSTRAINT quotation_sales_fkey FOREIGN KEY (sales_id)
+    REFERENCES public.sales (sales_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+CREATE TABLE IF NOT EXISTS public.quotation_line
+(
+    quotation_line_id bigserial NOT NULL,
+    quotation_id bigint NOT NULL,
+    product_id integer NOT NULL,
+    product_quantity integer NOT NULL,
+    unit_price bigint NOT NULL,
+    discount bigint NOT NULL DEFAULT 0,
+    discount_reason character varying COLLATE pg_catalog."default",
+    promotion_discount bigint NOT NULL DEFAULT 0,
+    tax_category public.tax_category NOT NULL DEFAULT 'standard',
+    tax_rate integer NOT NULL DEFAULT 0,
+    net_price bigint NOT NULL,
+    tax_amount bigint NOT NULL DEFAULT 0,
+    total_price bigint NOT NULL,
+    CONSTRAINT quotation_line_pkey PRIMARY KEY (quotation_line_id),
+    CONSTRAINT quotation_line_tax_check CHECK (net_price + tax_amount = total_price)
+);
+
+-- Customers, stores, sales representatives, products and quantities are read as 32-bit integers by the API, like on a sale.
+ALTER TABLE IF EXISTS public.quotation
+    ALTER COLUMN customer_id TYPE integer,
+    ALTER COLUMN store_id TYPE integer,
+    ALTER COLUMN sales_rep_id TYPE integer,
+    ALTER COLUMN sales_pair_id TYPE integer;
+
+ALTER TABLE IF EXISTS public.quotation_line
+    ALTER COLUMN product_id TYPE integer,
+    ALTER COLUMN product_quantity TYPE integer;
+
+CREATE INDEX IF NOT EXISTS quotation_line_quotation_id_idx ON public.quotation_line (quotation_id);
+
+ALTER TABLE IF EXISTS public.quotation_line DROP CONSTRAINT IF EXISTS quotation_line_quotation_fkey;
+
+ALTER TABLE IF EXISTS public.quotation_line
+    ADD CONSTRAINT quotation_line_quotation_fkey FOREIGN KEY (quotation_id)
+    REFERENCES public.quotation (quotation_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.quotation_line DROP CONSTRAINT IF EXISTS quotation_line_product_fkey;
+
+ALTER TABLE IF EXISTS public.quotation_line
+    ADD CONSTRAINT quotation_line_product_fkey FOREIGN KEY (product_id)
+    REFERENCES public.product (product_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+END;
@@ -0,0 +1,29 @@
+-- Responses to create requests made with an Idempotency-Key header, kept so that retries are answered without
+-- creating the record again.
+BEGIN;
+
+CREATE TABLE IF NOT EXISTS public.idempotency_key
+(
+    idempotency_key character varying COLLATE pg_catalog."default" NOT NULL,
+    request_path character varying COLLATE pg_catalog."default" NOT NULL,
+    request_body text COLLATE pg_catalog."default" NOT NULL,
+    response_status integer,
+    response_content_type character varying COLLATE pg_catalog."default",
+    response_location character varying COLLATE pg_catalog."default",
+    response_body bytea,
+    created_at timestamp without time zone NOT NULL,
+    claimed_at timestamp without time zone NOT NULL,
+    CONSTRAINT idempotency_key_pkey PRIMARY KEY (idempotency_key)
+);
+
+-- When the request handling a key claimed it, so claims left unanswered by a server that stopped can be given up when
+-- it starts again.
+ALTER TABLE IF EXISTS public.idempotency_key ADD COLUMN IF NOT EXISTS claimed_at timestamp without time zone;
+
+UPDATE public.idempotency_key SET claimed_at = created_at WHERE claimed_at IS NULL;
+
+ALTER TABLE IF EXISTS public.idempotency_key ALTER COLUMN claimed_at SET NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idempotency_key_created_at_idx ON public.idempotency_key (created_at);
+
+END;
@@ -0,0 +1,71 @@
+-- Offline sync: operations uploaded by rep devices are recorded so they apply at most once, and changes to the records
+-- devices keep a copy of are tracked so each sync only sends what changed since the device's last one.
+BEGIN;
+
+DO $$
+BEGIN
+    CREATE TYPE public.sync_status AS ENUM ('applied', 'conflict', 'rejected');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS public.sync_operation
+(
+    operation_id uuid NOT NULL,
+    device_id character varying COLLATE pg_catalog."default" NOT NULL,
+    kind character varying COLLATE pg_catalog."default" NOT NULL,
+    status public.sync_status NOT NULL,
+    entity_id bigint,
+    message character varying COLLATE pg_catalog."default",
+    synced_at timestamp without time zone NOT NULL DEFAULT now(),
+    CONSTRAINT sync_operation_pkey PRIMARY KEY (operation_id)
+);
+
+-- One row per record, holding the transaction that last created, changed or deleted it.
+CREATE TABLE IF NOT EXISTS public.sync_change
+(
+    entity character varying COLLATE pg_catalog."default" NOT NULL,
+    entity_id bigint NOT NULL,
+    txid xid8 NOT NULL DEFAULT pg_current_xact_id(),
+    deleted boolean NOT NULL DEFAULT false,
+    CONSTRAINT sync_change_pkey PRIMARY KEY (entity, entity_id)
+);
+
+CREATE INDEX IF NOT EXISTS sync_change_txid_idx ON public.sync_change (txid);
+
+-- TG_ARGV[0] is the entity name sent to devices, TG_ARGV[1] the table's ID column.
+CREATE OR REPLACE FUNCTION public.record_sync_change() RETURNS trigger AS $$
+DECLARE
+    row_data jsonb;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        row_data := to_jsonb(OLD);
+    ELSE
+        row_data := to_jsonb(NEW);
+    END IF;
+
+    INSERT INTO public.sync_change (entity, entity_id, txid, deleted)
+    VALUES (TG_ARGV[0], (row_data ->> TG_ARGV[1])::bigint, pg_current_xact_id(), TG_OP = 'DELETE')
+    ON CONFLICT (entity, entity_id) DO UPDATE SET txid = EXCLUDED.txid, deleted = EXCLUDED.deleted;
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS customer_sync_change ON public.customer;
+CREATE TRIGGER customer_sync_change AFTER INSERT OR UPDATE OR DELETE ON public.customer
+    FOR EACH ROW EXECUTE FUNCTION public.record_sync_change('customer', 'customer_id');
+
+DROP TRIGGER IF EXISTS product_sync_change ON public.product;
+CREATE TRIGGER product_sync_change AFTER INSERT OR UPDATE OR DELETE ON public.product
+    FOR EACH ROW EXECUTE FUNCTION public.record_sync_change('product', 'product_id');
+
+DROP TRIGGER IF EXISTS stock_record_sync_change ON public.stock_record;
+CREATE TRIGGER stock_record_sync_change AFTER INSERT OR UPDATE OR DELETE ON public.stock_record
+    FOR EACH ROW EXECUTE FUNCTION public.record_sync_change('stock_record', 'stock_id');
+
+DROP TRIGGER IF EXISTS debt_sync_change ON public.debt;
+CREATE TRIGGER debt_sync_change AFTER INSERT OR UPDATE OR DELETE ON public.debt
+    FOR EACH ROW EXECUTE FUNCTION public.record_sync_change('debt', 'debt_id');
+
+END;
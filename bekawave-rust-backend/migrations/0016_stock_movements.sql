@@ -0,0 +1,79 @@
+-- The stock ledger: every change to a stock_record quantity is appended here, with the quantity held after it.
+BEGIN;
+
+DO $$
+BEGIN
+    CREATE TYPE public.stock_movement_type AS ENUM ('receipt', 'sale', 'return', 'transfer', 'adjustment', 'write_off');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS public.stock_movement
+(
+    movement_id bigserial NOT NULL,
+    store_id integer NOT NULL,
+    product_id integer NOT NULL,
+    movement_type public.stock_movement_type NOT NULL,
+    quantity integer NOT NULL,
+    balance bigint NOT NULL,
+    reference_type character varying COLLATE pg_catalog."default",
+    reference_id bigint,
+    sales_rep_id integer,
+    note character varying COLLATE pg_catalog."default",
+    moved_at timestamp without time zone NOT NULL,
+    CONSTRAINT stock_movement_pkey PRIMARY KEY (movement_id)
+);
+
+-- Stores and products are read as 32-bit IDs by the API; quantities held and stock values stay 64-bit.
+ALTER TABLE IF EXISTS public.stock_record
+    ALTER COLUMN store_id TYPE integer,
+    ALTER COLUMN product_id TYPE integer;
+
+ALTER TABLE IF EXISTS public.stock_movement
+    ALTER COLUMN store_id TYPE integer,
+    ALTER COLUMN product_id TYPE integer,
+    ALTER COLUMN quantity TYPE integer,
+    ALTER COLUMN balance TYPE bigint,
+    ALTER COLUMN sales_rep_id TYPE integer;
+
+CREATE INDEX IF NOT EXISTS stock_movement_store_product_idx ON public.stock_movement (store_id, product_id, movement_id);
+CREATE INDEX IF NOT EXISTS stock_movement_reference_idx ON public.stock_movement (reference_type, reference_id);
+CREATE INDEX IF NOT EXISTS stock_movement_moved_at_idx ON public.stock_movement (moved_at);
+
+ALTER TABLE IF EXISTS public.stock_movement DROP CONSTRAINT IF EXISTS stock_movement_store_fkey;
+
+ALTER TABLE IF EXISTS public.stock_movement
+    ADD CONSTRAINT stock_movement_store_fkey FOREIGN KEY (store_id)
+    REFERENCES public.store (store_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.stock_movement DROP CONSTRAINT IF EXISTS stock_movement_product_fkey;
+
+ALTER TABLE IF EXISTS public.stock_movement
+    ADD CONSTRAINT stock_movement_product_fkey FOREIGN KEY (product_id)
+    REFERENCES public.product (product_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.stock_movement DROP CONSTRAINT IF EXISTS stock_movement_sales_rep_fkey;
+
+ALTER TABLE IF EXISTS public.stock_movement
+    ADD CONSTRAINT stock_movement_sales_rep_fkey FOREIGN KEY (sales_rep_id)
+    REFERENCES public.sales_rep (sales_rep_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE SET NULL;
+
+ALTER TABLE IF EXISTS public.stock_movement DROP CONSTRAINT IF EXISTS stock_movement_reference_check;
+
+ALTER TABLE IF EXISTS public.stock_movement
+    ADD CONSTRAINT stock_movement_reference_check CHECK ((reference_type IS NULL) = (reference_id IS NULL));
+
+-- Stock held before the ledger existed is brought in as an opening adjustment, so balances start from it.
+INSERT INTO public.stock_movement (store_id, product_id, movement_type, quantity, balance, note, moved_at)
+SELECT sr.store_id, sr.product_id, 'adjustment', sr.quantity, sr.quantity, 'Opening balance', now()
+FROM public.stock_record sr
+WHERE sr.quantity <> 0
+  AND NOT EXISTS (SELECT 1 FROM public.stock_movement sm WHERE sm.store_id = sr.store_id AND sm.product_id = sr.product_id);
+
+END;
@@ -0,0 +1,93 @@
+-- Transfers move stock from one store to another: requested, then dispatched from the source, then received at the
+-- destination, with any difference between what was sent and what arrived kept on the line.
+BEGIN;
+
+DO $$
+BEGIN
+    CREATE TYPE public.transfer_status AS ENUM ('requested', 'dispatched', 'received', 'cancelled');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS public.stock_transfer
+(
+    transfer_id bigserial NOT NULL,
+    from_store_id integer NOT NULL,
+    to_store_id integer NOT NULL,
+    status public.transfer_status NOT NULL DEFAULT 'requested',
+    note character varying COLLATE pg_catalog."default",
+    requested_by integer,
+    dispatched_by integer,
+    received_by integer,
+    requested_at timestamp without time zone NOT NULL,
+    dispatched_at timestamp without time zone,
+    received_at timestamp without time zone,
+    CONSTRAINT stock_transfer_pkey PRIMARY KEY (transfer_id),
+    CONSTRAINT stock_transfer_stores_check CHECK (from_store_id <> to_store_id)
+);
+
+CREATE TABLE IF NOT EXISTS public.stock_transfer_line
+(
+    transfer_line_id bigserial NOT NULL,
+    transfer_id bigint NOT NULL,
+    product_id integer NOT NULL,
+    quantity integer NOT NULL,
+    unit_worth integer,
+    received_quantity integer,
+    discrepancy_note character varying COLLATE pg_catalog."default",
+    CONSTRAINT stock_transfer_line_pkey PRIMARY KEY (transfer_line_id),
+    CONSTRAINT stock_transfer_line_product_key UNIQUE (transfer_id, product_id),
+    CONSTRAINT stock_transfer_line_quantity_check CHECK (quantity > 0 AND (received_quantity IS NULL OR received_quantity >= 0))
+);
+
+-- Stores, sales representatives, products and quantities are read as 32-bit integers by the API.
+ALTER TABLE IF EXISTS public.stock_transfer
+    ALTER COLUMN from_store_id TYPE integer,
+    ALTER COLUMN to_store_id TYPE integer,
+    ALTER COLUMN requested_by TYPE integer,
+    ALTER COLUMN dispatched_by TYPE integer,
+    ALTER COLUMN received_by TYPE integer;
+
+ALTER TABLE IF EXISTS public.stock_transfer_line
+    ALTER COLUMN product_id TYPE integer,
+    ALTER COLUMN quantity TYPE integer,
+    ALTER COLUMN unit_worth TYPE integer,
+    ALTER COLUMN received_quantity TYPE integer;
+
+CREATE INDEX IF NOT EXISTS stock_transfer_status_idx ON public.stock_transfer (status);
+CREATE INDEX IF NOT EXISTS stock_transfer_from_store_idx ON public.stock_transfer (from_store_id);
+CREATE INDEX IF NOT EXISTS stock_transfer_to_store_idx ON public.stock_transfer (to_store_id);
+
+ALTER TABLE IF EXISTS public.stock_transfer DROP CONSTRAINT IF EXISTS stock_transfer_from_store_fkey;
+
+ALTER TABLE IF EXISTS public.stock_transfer
+    ADD CONSTRAINT stock_transfer_from_store_fkey FOREIGN KEY (from_store_id)
+    REFERENCES public.store (store_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.stock_transfer DROP CONSTRAINT IF EXISTS stock_transfer_to_store_fkey;
+
+ALTER TABLE IF EXISTS public.stock_transfer
+    ADD CONSTRAINT stock_transfer_to_store_fkey FOREIGN KEY (to_store_id)
+    REFERENCES public.store (store_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.stock_transfer_line DROP CONSTRAINT IF EXISTS stock_transfer_line_transfer_fkey;
+
+ALTER TABLE IF EXISTS public.stock_transfer_line
+    ADD CONSTRAINT stock_transfer_line_transfer_fkey FOREIGN KEY (transfer_id)
+    REFERENCES public.stock_transfer (transfer_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.stock_transfer_line DROP CONSTRAINT IF EXISTS stock_transfer_line_product_fkey;
+
+ALTER TABLE IF EXISTS public.stock_transfer_line
+    ADD CONSTRAINT stock_transfer_line_product_fkey FOREIGN KEY (product_id)
+    REFERENCES public.product (product_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+END;
@@ -0,0 +1,68 @@
+-- Reorder levels per store and product, and the alerts raised when a stock movement takes stock below its level.
+BEGIN;
+
+CREATE TABLE IF NOT EXISTS public.reorder_level
+(
+    store_id integer NOT NULL,
+    product_id integer NOT NULL,
+    reorder_level integer NOT NULL,
+    reorder_quantity integer NOT NULL,
+    CONSTRAINT reorder_level_pkey PRIMARY KEY (store_id, product_id),
+    CONSTRAINT reorder_level_check CHECK (reorder_level >= 0 AND reorder_quantity > 0)
+);
+
+CREATE TABLE IF NOT EXISTS public.stock_alert
+(
+    alert_id bigserial NOT NULL,
+    store_id integer NOT NULL,
+    product_id integer NOT NULL,
+    movement_id bigint NOT NULL,
+    quantity integer NOT NULL,
+    reorder_level integer NOT NULL,
+    suggested_quantity integer NOT NULL,
+    raised_at timestamp without time zone NOT NULL,
+    acknowledged_at timestamp without time zone,
+    CONSTRAINT stock_alert_pkey PRIMARY KEY (alert_id)
+);
+
+-- Stores, products and quantities are read as 32-bit integers by the API, like the stock they watch.
+ALTER TABLE IF EXISTS public.reorder_level
+    ALTER COLUMN store_id TYPE integer,
+    ALTER COLUMN product_id TYPE integer,
+    ALTER COLUMN reorder_level TYPE integer,
+    ALTER COLUMN reorder_quantity TYPE integer;
+
+ALTER TABLE IF EXISTS public.stock_alert
+    ALTER COLUMN store_id TYPE integer,
+    ALTER COLUMN product_id TYPE integer,
+    ALTER COLUMN quantity TYPE integer,
+    ALTER COLUMN reorder_level TYPE integer,
+    ALTER COLUMN suggested_quantity TYPE integer;
+
+CREATE INDEX IF NOT EXISTS stock_alert_open_idx ON public.stock_alert (store_id) WHERE acknowledged_at IS NULL;
+
+ALTER TABLE IF EXISTS public.reorder_level DROP CONSTRAINT IF EXISTS reorder_level_store_fkey;
+
+ALTER TABLE IF EXISTS public.reorder_level
+    ADD CONSTRAINT reorder_level_store_fkey FOREIGN KEY (store_id)
+    REFERENCES public.store (store_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.reorder_level DROP CONSTRAINT IF EXISTS reorder_level_product_fkey;
+
+ALTER TABLE IF EXISTS public.reorder_level
+    ADD CONSTRAINT reorder_level_product_fkey FOREIGN KEY (product_id)
+    REFERENCES public.product (product_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.stock_alert DROP CONSTRAINT IF EXISTS stock_alert_movement_fkey;
+
+ALTER TABLE IF EXISTS public.stock_alert
+    ADD CONSTRAINT stock_alert_movement_fkey FOREIGN KEY (movement_id)
+    REFERENCES public.stock_movement (movement_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+END;
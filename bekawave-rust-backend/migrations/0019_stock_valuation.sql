@@ -0,0 +1,25 @@
+-- Stock is valued from what goods cost to receive: each movement bringing goods in at a known cost records it.
+BEGIN;
+
+ALTER TABLE IF EXISTS public.stock_movement ADD COLUMN IF NOT EXISTS unit_cost bigint;
+
+ALTER TABLE IF EXISTS public.stock_movement DROP CONSTRAINT IF EXISTS stock_movement_unit_cost_check;
+
+ALTER TABLE IF EXISTS public.stock_movement
+    ADD CONSTRAINT stock_movement_unit_cost_check CHECK (unit_cost >= 0);
+
+-- Receipts and opening balances recorded before costs were kept are costed at the worth of their stock record, the
+-- best figure there is for them.
+UPDATE public.stock_movement sm
+SET unit_cost = GREATEST(sr.product_worth, 0)
+FROM public.stock_record sr
+WHERE sr.store_id = sm.store_id
+  AND sr.product_id = sm.product_id
+  AND sm.unit_cost IS NULL
+  AND sm.quantity > 0
+  AND (sm.movement_type = 'receipt' OR (sm.movement_type = 'adjustment' AND sm.note = 'Opening balance'));
+
+-- Costing a few products replays only their movements, in every store.
+CREATE INDEX IF NOT EXISTS stock_movement_product_idx ON public.stock_movement (product_id, movement_id);
+
+END;
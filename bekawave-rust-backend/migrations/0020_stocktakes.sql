@@ -0,0 +1,111 @@
+-- Stocktakes count a store's stock against the quantities frozen when the count was opened, and keep the counts and
+-- variances after closing as the stocktake's report.
+BEGIN;
+
+DO $$
+BEGIN
+    CREATE TYPE public.stocktake_status AS ENUM ('open', 'closed', 'cancelled');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS public.stocktake
+(
+    stocktake_id bigserial NOT NULL,
+    store_id integer NOT NULL,
+    status public.stocktake_status NOT NULL DEFAULT 'open',
+    note character varying COLLATE pg_catalog."default",
+    opened_by integer,
+    closed_by integer,
+    opened_at timestamp without time zone NOT NULL,
+    closed_at timestamp without time zone,
+    CONSTRAINT stocktake_pkey PRIMARY KEY (stocktake_id)
+);
+
+CREATE TABLE IF NOT EXISTS public.stocktake_line
+(
+    stocktake_line_id bigserial NOT NULL,
+    stocktake_id bigint NOT NULL,
+    product_id integer NOT NULL,
+    expected_quantity integer NOT NULL,
+    unit_cost bigint NOT NULL DEFAULT 0,
+    counted_quantity integer,
+    CONSTRAINT stocktake_line_pkey PRIMARY KEY (stocktake_line_id),
+    CONSTRAINT stocktake_line_product_key UNIQUE (stocktake_id, product_id),
+    CONSTRAINT stocktake_line_counted_check CHECK (counted_quantity IS NULL OR counted_quantity >= 0)
+);
+
+CREATE TABLE IF NOT EXISTS public.stocktake_count
+(
+    count_id bigserial NOT NULL,
+    stocktake_id bigint NOT NULL,
+    product_id integer NOT NULL,
+    quantity integer NOT NULL,
+    counted_by integer,
+    note character varying COLLATE pg_catalog."default",
+    counted_at timestamp without time zone NOT NULL,
+    CONSTRAINT stocktake_count_pkey PRIMARY KEY (count_id),
+    CONSTRAINT stocktake_count_quantity_check CHECK (quantity >= 0)
+);
+
+-- Stores, sales representatives, products and quantities are read as 32-bit integers by the API, like the stock they count.
+ALTER TABLE IF EXISTS public.stocktake
+    ALTER COLUMN store_id TYPE integer,
+    ALTER COLUMN opened_by TYPE integer,
+    ALTER COLUMN closed_by TYPE integer;
+
+ALTER TABLE IF EXISTS public.stocktake_line
+    ALTER COLUMN product_id TYPE integer,
+    ALTER COLUMN expected_quantity TYPE integer,
+    ALTER COLUMN counted_quantity TYPE integer;
+
+ALTER TABLE IF EXISTS public.stocktake_count
+    ALTER COLUMN product_id TYPE integer,
+    ALTER COLUMN quantity TYPE integer,
+    ALTER COLUMN counted_by TYPE integer;
+
+-- A store is counted by one stocktake at a time.
+CREATE UNIQUE INDEX IF NOT EXISTS stocktake_open_store_idx ON public.stocktake (store_id) WHERE status = 'open';
+CREATE INDEX IF NOT EXISTS stocktake_count_product_idx ON public.stocktake_count (stocktake_id, product_id);
+
+ALTER TABLE IF EXISTS public.stocktake DROP CONSTRAINT IF EXISTS stocktake_store_fkey;
+
+ALTER TABLE IF EXISTS public.stocktake
+    ADD CONSTRAINT stocktake_store_fkey FOREIGN KEY (store_id)
+    REFERENCES public.store (store_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.stocktake_line DROP CONSTRAINT IF EXISTS stocktake_line_stocktake_fkey;
+
+ALTER TABLE IF EXISTS public.stocktake_line
+    ADD CONSTRAINT stocktake_line_stocktake_fkey FOREIGN KEY (stocktake_id)
+    REFERENCES public.stocktake (stocktake_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.stocktake_line DROP CONSTRAINT IF EXISTS stocktake_line_product_fkey;
+
+ALTER TABLE IF EXISTS public.stocktake_line
+    ADD CONSTRAINT stocktake_line_product_fkey FOREIGN KEY (product_id)
+    REFERENCES public.product (product_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.stocktake_count DROP CONSTRAINT IF EXISTS stocktake_count_line_fkey;
+
+ALTER TABLE IF EXISTS public.stocktake_count
+    ADD CONSTRAINT stocktake_count_line_fkey FOREIGN KEY (stocktake_id, product_id)
+    REFERENCES public.stocktake_line (stocktake_id, product_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.stocktake_count DROP CONSTRAINT IF EXISTS stocktake_count_sales_rep_fkey;
+
+ALTER TABLE IF EXISTS public.stocktake_count
+    ADD CONSTRAINT stocktake_count_sales_rep_fkey FOREIGN KEY (counted_by)
+    REFERENCES public.sales_rep (sales_rep_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE SET NULL;
+
+END;
@@ -0,0 +1,156 @@
+-- Suppliers and purchase orders. Goods arrive against an order on goods-received notes, which take them into the
+-- order's store at their actual cost and may deliver less or more than was ordered.
+BEGIN;
+
+DO $$
+BEGIN
+    CREATE TYPE public.purchase_order_status AS ENUM ('ordered', 'partially_received', 'received', 'closed', 'cancelled');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS public.supplier
+(
+    supplier_id bigserial NOT NULL,
+    name character varying COLLATE pg_catalog."default" NOT NULL,
+    phone_no character varying COLLATE pg_catalog."default",
+    email character varying COLLATE pg_catalog."default",
+    location character varying COLLATE pg_catalog."default",
+    CONSTRAINT supplier_pkey PRIMARY KEY (supplier_id)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS supplier_name_idx ON public.supplier (lower(name));
+
+CREATE TABLE IF NOT EXISTS public.purchase_order
+(
+    purchase_order_id bigserial NOT NULL,
+    supplier_id integer NOT NULL,
+    store_id integer NOT NULL,
+    status public.purchase_order_status NOT NULL DEFAULT 'ordered',
+    expected_date date,
+    note character varying COLLATE pg_catalog."default",
+    ordered_by integer,
+    ordered_at timestamp without time zone NOT NULL,
+    closed_at timestamp without time zone,
+    CONSTRAINT purchase_order_pkey PRIMARY KEY (purchase_order_id)
+);
+
+CREATE TABLE IF NOT EXISTS public.purchase_order_line
+(
+    purchase_order_line_id bigserial NOT NULL,
+    purchase_order_id bigint NOT NULL,
+    product_id integer NOT NULL,
+    quantity integer NOT NULL,
+    unit_cost bigint NOT NULL,
+    received_quantity integer NOT NULL DEFAULT 0,
+    CONSTRAINT purchase_order_line_pkey PRIMARY KEY (purchase_order_line_id),
+    CONSTRAINT purchase_order_line_product_key UNIQUE (purchase_order_id, product_id),
+    CONSTRAINT purchase_order_line_quantity_check CHECK (quantity > 0 AND unit_cost >= 0 AND received_quantity >= 0)
+);
+
+CREATE TABLE IF NOT EXISTS public.goods_received_note
+(
+    goods_received_note_id bigserial NOT NULL,
+    purchase_order_id bigint NOT NULL,
+    store_id integer NOT NULL,
+    delivery_note character varying COLLATE pg_catalog."default",
+    note character varying COLLATE pg_catalog."default",
+    received_by integer,
+    received_at timestamp without time zone NOT NULL,
+    CONSTRAINT goods_received_note_pkey PRIMARY KEY (goods_received_note_id)
+);
+
+CREATE TABLE IF NOT EXISTS public.goods_received_line
+(
+    goods_received_line_id bigserial NOT NULL,
+    goods_received_note_id bigint NOT NULL,
+    purchase_order_line_id bigint NOT NULL,
+    product_id integer NOT NULL,
+    quantity integer NOT NULL,
+    unit_cost bigint NOT NULL,
+    CONSTRAINT goods_received_line_pkey PRIMARY KEY (goods_received_line_id),
+    CONSTRAINT goods_received_line_quantity_check CHECK (quantity > 0 AND unit_cost >= 0)
+);
+
+-- Suppliers, stores, sales representatives, products and quantities are read as 32-bit integers by the API.
+ALTER TABLE IF EXISTS public.purchase_order
+    ALTER COLUMN supplier_id TYPE integer,
+    ALTER COLUMN store_id TYPE integer,
+    ALTER COLUMN ordered_by TYPE integer;
+
+ALTER TABLE IF EXISTS public.purchase_order_line
+    ALTER COLUMN product_id TYPE integer,
+    ALTER COLUMN quantity TYPE integer,
+    ALTER COLUMN received_quantity TYPE integer;
+
+ALTER TABLE IF EXISTS public.goods_received_note
+    ALTER COLUMN store_id TYPE integer,
+    ALTER COLUMN received_by TYPE integer;
+
+ALTER TABLE IF EXISTS public.goods_received_line
+    ALTER COLUMN product_id TYPE integer,
+    ALTER COLUMN quantity TYPE integer;
+
+CREATE INDEX IF NOT EXISTS purchase_order_status_idx ON public.purchase_order (status);
+CREATE INDEX IF NOT EXISTS purchase_order_supplier_idx ON public.purchase_order (supplier_id);
+CREATE INDEX IF NOT EXISTS purchase_order_store_idx ON public.purchase_order (store_id);
+CREATE INDEX IF NOT EXISTS goods_received_note_order_idx ON public.goods_received_note (purchase_order_id);
+CREATE INDEX IF NOT EXISTS goods_received_line_order_line_idx ON public.goods_received_line (purchase_order_line_id);
+
+ALTER TABLE IF EXISTS public.purchase_order DROP CONSTRAINT IF EXISTS purchase_order_supplier_fkey;
+
+ALTER TABLE IF EXISTS public.purchase_order
+    ADD CONSTRAINT purchase_order_supplier_fkey FOREIGN KEY (supplier_id)
+    REFERENCES public.supplier (supplier_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.purchase_order DROP CONSTRAINT IF EXISTS purchase_order_store_fkey;
+
+ALTER TABLE IF EXISTS public.purchase_order
+    ADD CONSTRAINT purchase_order_store_fkey FOREIGN KEY (store_id)
+    REFERENCES public.store (store_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.purchase_order_line DROP CONSTRAINT IF EXISTS purchase_order_line_order_fkey;
+
+ALTER TABLE IF EXISTS public.purchase_order_line
+    ADD CONSTRAINT purchase_order_line_order_fkey FOREIGN KEY (purchase_order_id)
+    REFERENCES public.purchase_order (purchase_order_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.purchase_order_line DROP CONSTRAINT IF EXISTS purchase_order_line_product_fkey;
+
+ALTER TABLE IF EXISTS public.purchase_order_line
+    ADD CONSTRAINT purchase_order_line_product_fkey FOREIGN KEY (product_id)
+    REFERENCES public.product (product_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.goods_received_note DROP CONSTRAINT IF EXISTS goods_received_note_order_fkey;
+
+ALTER TABLE IF EXISTS public.goods_received_note
+    ADD CONSTRAINT goods_received_note_order_fkey FOREIGN KEY (purchase_order_id)
+    REFERENCES public.purchase_order (purchase_order_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.goods_received_line DROP CONSTRAINT IF EXISTS goods_received_line_note_fkey;
+
+ALTER TABLE IF EXISTS public.goods_received_line
+    ADD CONSTRAINT goods_received_line_note_fkey FOREIGN KEY (goods_received_note_id)
+    REFERENCES public.goods_received_note (goods_received_note_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.goods_received_line DROP CONSTRAINT IF EXISTS goods_received_line_order_line_fkey;
+
+ALTER TABLE IF EXISTS public.goods_received_line
+    ADD CONSTRAINT goods_received_line_order_line_fkey FOREIGN KEY (purchase_order_line_id)
+    REFERENCES public.purchase_order_line (purchase_order_line_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+END;
@@ -0,0 +1,57 @@
+-- Lots: batches of a product held under a store's stock record, with manufacture and expiry dates. Each stock movement
+-- records which lots its goods went into or came out of.
+BEGIN;
+
+CREATE TABLE IF NOT EXISTS public.stock_lot
+(
+    lot_id bigserial NOT NULL,
+    stock_id bigint NOT NULL,
+    lot_number character varying COLLATE pg_catalog."default" NOT NULL,
+    manufacture_date date,
+    expiry_date date,
+    quantity bigint NOT NULL DEFAULT 0,
+    received_at timestamp without time zone NOT NULL,
+    CONSTRAINT stock_lot_pkey PRIMARY KEY (lot_id),
+    CONSTRAINT stock_lot_number_key UNIQUE (stock_id, lot_number),
+    CONSTRAINT stock_lot_quantity_check CHECK (quantity >= 0),
+    CONSTRAINT stock_lot_dates_check CHECK (expiry_date >= manufacture_date)
+);
+
+CREATE TABLE IF NOT EXISTS public.stock_movement_lot
+(
+    movement_id bigint NOT NULL,
+    lot_id bigint NOT NULL,
+    quantity bigint NOT NULL,
+    CONSTRAINT stock_movement_lot_pkey PRIMARY KEY (movement_id, lot_id)
+);
+
+CREATE INDEX IF NOT EXISTS stock_lot_expiry_idx ON public.stock_lot (expiry_date) WHERE quantity > 0;
+CREATE INDEX IF NOT EXISTS stock_movement_lot_lot_idx ON public.stock_movement_lot (lot_id);
+
+ALTER TABLE IF EXISTS public.goods_received_line ADD COLUMN IF NOT EXISTS lot_number character varying COLLATE pg_catalog."default";
+
+ALTER TABLE IF EXISTS public.stock_lot DROP CONSTRAINT IF EXISTS stock_lot_stock_fkey;
+
+ALTER TABLE IF EXISTS public.stock_lot
+    ADD CONSTRAINT stock_lot_stock_fkey FOREIGN KEY (stock_id)
+    REFERENCES public.stock_record (stock_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+ALTER TABLE IF EXISTS public.stock_movement_lot DROP CONSTRAINT IF EXISTS stock_movement_lot_movement_fkey;
+
+ALTER TABLE IF EXISTS public.stock_movement_lot
+    ADD CONSTRAINT stock_movement_lot_movement_fkey FOREIGN KEY (movement_id)
+    REFERENCES public.stock_movement (movement_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE NO ACTION;
+
+ALTER TABLE IF EXISTS public.stock_movement_lot DROP CONSTRAINT IF EXISTS stock_movement_lot_lot_fkey;
+
+ALTER TABLE IF EXISTS public.stock_movement_lot
+    ADD CONSTRAINT stock_movement_lot_lot_fkey FOREIGN KEY (lot_id)
+    REFERENCES public.stock_lot (lot_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+END;
@@ -0,0 +1,25 @@
+-- Units of measure: every product is kept in a base unit and may be bought, stored or sold in other units, each holding
+-- a whole number of base units. Stock, sales and the stock ledger stay in base units.
+BEGIN;
+
+ALTER TABLE IF EXISTS public.product ADD COLUMN IF NOT EXISTS base_unit character varying COLLATE pg_catalog."default" NOT NULL DEFAULT 'piece';
+
+CREATE TABLE IF NOT EXISTS public.product_unit
+(
+    product_id bigint NOT NULL,
+    unit character varying COLLATE pg_catalog."default" NOT NULL,
+    factor bigint NOT NULL,
+    CONSTRAINT product_unit_factor_check CHECK (factor > 0)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS product_unit_unit_key ON public.product_unit (product_id, lower(unit));
+
+ALTER TABLE IF EXISTS public.product_unit DROP CONSTRAINT IF EXISTS product_unit_product_fkey;
+
+ALTER TABLE IF EXISTS public.product_unit
+    ADD CONSTRAINT product_unit_product_fkey FOREIGN KEY (product_id)
+    REFERENCES public.product (product_id) MATCH SIMPLE
+    ON UPDATE NO ACTION
+    ON DELETE CASCADE;
+
+END;
@@ -0,0 +1,95 @@
+use std::env;
+use std::str::FromStr;
+use tracing::info;
+use crate::models::{BusinessDetails, TaxSettings, ValuationMethod};
+
+/// Runtime settings of the backend.
+///
+/// Values are read from environment variables, with a `.env` file in the working directory loaded first when present.
+/// Any variable that is missing or cannot be parsed falls back to its default.
+///
+/// # Fields
+/// - `allow_negative_stock`: When `true`, sales may take a store's stock below zero instead of failing (`ALLOW_NEGATIVE_STOCK`, default `false`).
+/// - `default_store_id`: The store that sales recorded before stores were tracked are attributed to (`DEFAULT_STORE_ID`, default `1`).
+/// - `business_name`: The business name printed on invoices (`BUSINESS_NAME`, default `Bekawave`).
+/// - `business_address`: The business address printed on invoices (`BUSINESS_ADDRESS`, default empty).
+/// - `business_phone`: The business phone number printed on invoices (`BUSINESS_PHONE`, default empty).
+/// - `vat_standard_rate`: The standard VAT rate in basis points (`VAT_STANDARD_RATE`, default `1600`, i.e. 16%).
+/// - `prices_include_tax`: Whether product prices include VAT (`PRICES_INCLUDE_TAX`, default `true`).
+/// - `idempotency_window_hours`: How long the response to a request made with an `Idempotency-Key` is kept for replay
+///   (`IDEMPOTENCY_WINDOW_HOURS`, default `24`).
+/// - `valuation_method`: How stock and the cost of goods sold are valued, `fifo` or `weighted_average`
+///   (`VALUATION_METHOD`, default `weighted_average`).
+/// - `lot_expiry_warning_days`: How many days ahead the expiring lots report looks when no period is asked for
+///   (`LOT_EXPIRY_WARNING_DAYS`, default `30`).
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub allow_negative_stock: bool,
+    pub default_store_id: i32,
+    pub business_name: String,
+    pub business_address: String,
+    pub business_phone: String,
+    pub vat_standard_rate: i32,
+    pub prices_include_tax: bool,
+    pub idempotency_window_hours: i64,
+    pub valuation_method: ValuationMethod,
+    pub lot_expiry_warning_days: i64,
+}
+
+impl Config {
+    /// Loads the configuration from the environment.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crate::config::Config;
+    ///
+    /// std::env::set_var("ALLOW_NEGATIVE_STOCK", "true");
+    /// let config = Config::from_env();
+    /// assert!(config.allow_negative_stock);
+    /// ```
+    pub fn from_env() -> Self {
+        dotenv::dotenv().ok();
+
+        let config = Config {
+            allow_negative_stock: read_var("ALLOW_NEGATIVE_STOCK", false),
+            default_store_id: read_var("DEFAULT_STORE_ID", 1),
+            business_name: read_var("BUSINESS_NAME", "Bekawave".to_string()),
+            business_address: read_var("BUSINESS_ADDRESS", String::new()),
+            business_phone: read_var("BUSINESS_PHONE", String::new()),
+            vat_standard_rate: read_var("VAT_STANDARD_RATE", 1600),
+            prices_include_tax: read_var("PRICES_INCLUDE_TAX", true),
+            idempotency_window_hours: read_var("IDEMPOTENCY_WINDOW_HOURS", 24),
+            valuation_method: read_var("VALUATION_METHOD", ValuationMethod::WeightedAverage),
+            lot_expiry_warning_days: read_var("LOT_EXPIRY_WARNING_DAYS", 30),
+        };
+
+        info!("Loaded configuration: {:?}", config);
+        config
+    }
+
+    /// The business details printed at the top of invoices.
+    pub fn business_details(&self) -> BusinessDetails {
+        BusinessDetails {
+            name: self.business_name.clone(),
+            address: self.business_address.clone(),
+            phone: self.business_phone.clone(),
+        }
+    }
+
+    /// The VAT rules sales are priced under.
+    pub fn tax_settings(&self) -> TaxSettings {
+        TaxSettings {
+            standard_rate: self.vat_standard_rate,
+            prices_include_tax: self.prices_include_tax,
+        }
+    }
+}
+
+/// Reads and parses an environment variable, returning `default` if it is unset or invalid.
+fn read_var<T: FromStr>(name: &str, default: T) -> T {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(default)
+}
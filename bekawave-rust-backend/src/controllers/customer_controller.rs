@@ -6,6 +6,7 @@ use sqlx::PgPool;
 use tracing::info;
 use crate::services::{CustomerError, CustomerService};
 use crate::models::{Customer, ErrorResponse};
+use crate::controllers::Idempotent;
 
 /// Initializes a `CustomerService` instance using the provided `PgPool`.
 pub async fn customer_service_init(pool: &PgPool) -> CustomerService {
@@ -61,7 +62,7 @@ pub async fn get_customer(customer_service: &State<CustomerService>, id: i32) ->
 
 /// Creates a new customer.
 #[post("/customers/create", data = "<customer>")]
-pub async fn create_customer(customer_service: &State<CustomerService>, customer: Json<Customer>) -> Result<Created<Json<Customer>>, (Status, Json<ErrorResponse>)> {
+pub async fn create_customer(customer_service: &State<CustomerService>, customer: Idempotent<Customer>) -> Result<Created<Json<Customer>>, (Status, Json<ErrorResponse>)> {
     match customer_service.create_customer(customer.into_inner()).await {
         Ok(new_customer) => {
             info!("Created customer successfully");
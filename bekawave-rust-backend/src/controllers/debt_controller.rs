@@ -5,7 +5,8 @@ use rocket::State;
 use sqlx::PgPool;
 use tracing::info;
 use crate::services::{DebtError, DebtService};
-use crate::models::{ErrorResponse, Debt};
+use crate::models::{ErrorResponse, Debt, DebtWithGoods};
+use crate::controllers::Idempotent;
 
 /// Initializes a `DebtService` instance using the provided `PgPool`.
 pub async fn debt_service_init(pool: &PgPool) -> DebtService {
@@ -65,9 +66,29 @@ pub async fn get_debt(debt_service: &State<DebtService>, id: i32) -> Result<Json
     }
 }
 
+/// Retrieves a customer's debts with the goods behind each of them.
+#[get("/debts/by-customer/<customer_id>")]
+pub async fn get_customer_debts(debt_service: &State<DebtService>, customer_id: i32) -> Result<Json<Vec<DebtWithGoods>>, (Status, Json<ErrorResponse>)> {
+    match debt_service.get_customer_debts(customer_id).await {
+        Ok(debts) => {
+            info!("Retrieved {} debts for customer with ID: {}", debts.len(), customer_id);
+            Ok(Json(debts))
+        }
+        Err(e) => {
+            info!("Error retrieving debts of customer with ID {}: {:?}", customer_id, e);
+            Err((
+                Status::InternalServerError,
+                Json(ErrorResponse {
+                    message: "Failed to retrieve customer debts".to_string(),
+                }),
+            ))
+        }
+    }
+}
+
 /// Creates a new debt.
 #[post("/debts/create", data = "<debt>")]
-pub async fn create_debt(debt_service: &State<DebtService>, debt: Json<Debt>) -> Result<Created<Json<Debt>>, (Status, Json<ErrorResponse>)> {
+pub async fn create_debt(debt_service: &State<DebtService>, debt: Idempotent<Debt>) -> Result<Created<Json<Debt>>, (Status, Json<ErrorResponse>)> {
     match debt_service.create_debt(debt.into_inner()).await {
         Ok(new_debt) => {
             info!("Created debt successfully");
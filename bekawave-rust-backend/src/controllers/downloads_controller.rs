@@ -1,15 +1,64 @@
+use rocket::http::Status;
+use rocket::serde::json::Json;
 use rocket::State;
-use crate::services::{CustomerService, download_file, ProductService, SalesService};
+use crate::models::{ErrorResponse, QuotationStatus};
+use crate::services::{CustomerService, download_file, download_quotations, DownloadedFile, ProductService, QuotationError, QuotationService, SalesError, SalesService, StoreService};
+use tracing::info;
 
-#[get("/download?<format>")]
+#[get("/download?<format>&<store_id>")]
 pub async fn download_route(
     format: &str,
+    store_id: Option<i32>,
     customer_service: &State<CustomerService>,
     product_service: &State<ProductService>,
     sales_service: &State<SalesService>,
-) -> Result<String, String> {
-    match download_file(product_service, customer_service, sales_service, format).await {
-        Ok(response) => Ok(format!("File generated successfully: {:?}", response)),
-        Err(e) => Err(format!("Failed to generate file: {:?}", e)),
+    store_service: &State<StoreService>,
+) -> Result<DownloadedFile, (Status, Json<ErrorResponse>)> {
+    match download_file(product_service, customer_service, sales_service, store_service, format, store_id).await {
+        Ok(response) => {
+            info!("Generated {} file of sales", format);
+            Ok(response)
+        }
+        Err(e @ SalesError::UnsupportedFormat) => {
+            info!("Generating sales file failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: format!("Unsupported format '{}'; use csv or xlsx", format),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to generate sales file: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to generate sales file".to_string(),
+            })))
+        }
     }
-}
\ No newline at end of file
+}
+
+#[get("/quotations/download?<format>&<status>")]
+pub async fn download_quotations_route(
+    format: &str,
+    status: Option<QuotationStatus>,
+    customer_service: &State<CustomerService>,
+    product_service: &State<ProductService>,
+    quotation_service: &State<QuotationService>,
+    store_service: &State<StoreService>,
+) -> Result<DownloadedFile, (Status, Json<ErrorResponse>)> {
+    match download_quotations(product_service, customer_service, quotation_service, store_service, format, status).await {
+        Ok(response) => {
+            info!("Generated {} file of quotations", format);
+            Ok(response)
+        }
+        Err(e @ QuotationError::UnsupportedFormat) => {
+            info!("Generating quotations file failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: format!("Unsupported format '{}'; use csv or xlsx", format),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to generate quotations file: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to generate quotations file".to_string(),
+            })))
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::io::Cursor;
+use chrono::NaiveDateTime;
+use std::ops::Deref;
+use rocket::data::{self, Data, FromData, Limits};
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::http::{ContentType, Header, Status};
+use rocket::outcome::Outcome;
+use rocket::{Request, Response};
+use serde::de::DeserializeOwned;
+use sqlx::PgPool;
+use tracing::info;
+use crate::config::Config;
+use crate::models::{ErrorResponse, IdempotencyRecord, IDEMPOTENCY_KEY_HEADER, IDEMPOTENCY_KEY_MAX_LENGTH};
+use crate::services::{IdempotencyClaim, IdempotencyError, IdempotencyService};
+
+/// Initializes an `IdempotencyService` instance using the provided `PgPool` and the configured window.
+pub async fn idempotency_service_init(pool: &PgPool, config: &Config) -> IdempotencyService {
+    IdempotencyService::new(pool.clone(), config.idempotency_window_hours).await
+}
+
+/// A JSON request body that honours the `Idempotency-Key` header.
+///
+/// Used in place of `Json<T>` on create routes. Without the header it behaves like `Json<T>`. With it, the first request
+/// made with a key is handled as usual and its response is kept by the `Idempotency` fairing; repeating the request with
+/// the same key gives that response again without running the route. Reusing a key for a different request is
+/// answered with `422 Unprocessable Entity`, and repeating it while the first request is still being handled with
+/// `409 Conflict`. Keys a request was still holding when the server stopped are given up when it starts again.
+#[derive(Debug)]
+pub struct Idempotent<T>(pub T);
+
+impl<T> Idempotent<T> {
+    /// Consumes the wrapper and returns the request body.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Idempotent<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// What the `Idempotency` fairing has to do with the response to a request.
+enum IdempotencyState {
+    /// The request has no idempotency key.
+    Unused,
+    /// The request claimed the key at the given time; its response is to be kept.
+    Claimed(String, NaiveDateTime),
+    /// The request repeats an answered one; the kept response is given instead.
+    Replay(IdempotencyRecord),
+    /// The key cannot be used for the request.
+    Rejected(Status, String),
+}
+
+#[rocket::async_trait]
+impl<'r, T: DeserializeOwned + Send> FromData<'r> for Idempotent<T> {
+    type Error = String;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        let limit = req.limits().get("json").unwrap_or(Limits::JSON);
+        let body = match data.open(limit).into_string().await {
+            Ok(body) if body.is_complete() => body.into_inner(),
+            Ok(_) => return Outcome::Error((Status::PayloadTooLarge, "Request body is too large".to_string())),
+            Err(e) => return Outcome::Error((Status::BadRequest, e.to_string())),
+        };
+        let value = match serde_json::from_str::<T>(&body) {
+            Ok(value) => value,
+            Err(e) => return Outcome::Error((Status::UnprocessableEntity, e.to_string())),
+        };
+
+        let key = match req.headers().get_one(IDEMPOTENCY_KEY_HEADER) {
+            Some(key) => key.trim(),
+            None => return Outcome::Success(Idempotent(value)),
+        };
+        if key.is_empty() || key.len() > IDEMPOTENCY_KEY_MAX_LENGTH {
+            let message = format!("{} must be between 1 and {} characters", IDEMPOTENCY_KEY_HEADER, IDEMPOTENCY_KEY_MAX_LENGTH);
+            return reject(req, Status::BadRequest, message);
+        }
+
+        let idempotency_service = req.rocket().state::<IdempotencyService>().expect("IdempotencyService is not managed");
+        match idempotency_service.begin(key, req.uri().path().as_str(), &body).await {
+            Ok(IdempotencyClaim::Claimed(claimed_at)) => {
+                req.local_cache(|| IdempotencyState::Claimed(key.to_string(), claimed_at));
+                Outcome::Success(Idempotent(value))
+            }
+            Ok(IdempotencyClaim::Answered(record)) => {
+                // The route is skipped; the fairing swaps the error response for the kept one.
+                req.local_cache(|| IdempotencyState::Replay(record));
+                Outcome::Error((Status::Conflict, format!("Replaying the response to {}", key)))
+            }
+            Err(e @ IdempotencyError::KeyReused) => reject(req, Status::UnprocessableEntity, e.to_string()),
+            Err(e @ IdempotencyError::InProgress) => reject(req, Status::Conflict, e.to_string()),
+            Err(e) => {
+                info!("Failed to check idempotency key {}: {:?}", key, e);
+                reject(req, Status::InternalServerError, "Failed to check idempotency key".to_string())
+            }
+        }
+    }
+}
+
+/// Stops a request before its route runs and has the `Idempotency` fairing answer it with `message`.
+fn reject<'r, T>(req: &'r Request<'_>, status: Status, message: String) -> data::Outcome<'r, Idempotent<T>, String> {
+    info!("Rejected idempotency key: {}", message);
+    req.local_cache(|| IdempotencyState::Rejected(status, message.clone()));
+    Outcome::Error((status, message))
+}
+
+/// Keeps the responses to requests that claimed an idempotency key and gives them again to repeated requests.
+///
+/// Works together with the `Idempotent<T>` request body, which decides what happens to each request.
+pub struct Idempotency;
+
+#[rocket::async_trait]
+impl Fairing for Idempotency {
+    fn info(&self) -> Info {
+        Info {
+            name: "Idempotency keys",
+            kind: Kind::Response,
+        }
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        match req.local_cache(|| IdempotencyState::Unused) {
+            IdempotencyState::Unused => {}
+            IdempotencyState::Claimed(key, claimed_at) => {
+                let idempotency_service = req.rocket().state::<IdempotencyService>().expect("IdempotencyService is not managed");
+                let kept = if res.status().class().is_server_error() {
+                    idempotency_service.release(key, *claimed_at).await
+                } else {
+                    let body = res.body_mut().to_bytes().await.unwrap_or_default();
+                    let content_type = res.content_type().map(|content_type| content_type.to_string());
+                    let location = res.headers().get_one("Location").map(str::to_string);
+                    let kept = idempotency_service.complete(key, *claimed_at, res.status().code, content_type, location, &body).await;
+                    res.set_sized_body(body.len(), Cursor::new(body));
+                    kept
+                };
+                if let Err(e) = kept {
+                    info!("Failed to keep the response to idempotency key {}: {:?}", key, e);
+                }
+            }
+            IdempotencyState::Replay(record) => {
+                let body = record.response_body.clone().unwrap_or_default();
+                res.set_status(record.response_status.and_then(|code| Status::from_code(code as u16)).unwrap_or(Status::Ok));
+                if let Some(content_type) = record.response_content_type.as_deref().and_then(ContentType::parse_flexible) {
+                    res.set_header(content_type);
+                }
+                if let Some(location) = &record.response_location {
+                    res.set_header(Header::new("Location", location.clone()));
+                }
+                res.set_header(Header::new("Idempotent-Replayed", "true"));
+                res.set_sized_body(body.len(), Cursor::new(body));
+            }
+            IdempotencyState::Rejected(status, message) => {
+                let body = serde_json::to_vec(&ErrorResponse { message: message.clone() }).unwrap_or_default();
+                res.set_status(*status);
+                res.set_header(ContentType::JSON);
+                res.set_sized_body(body.len(), Cursor::new(body));
+            }
+        }
+    }
+}
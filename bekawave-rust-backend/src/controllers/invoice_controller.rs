@@ -0,0 +1,35 @@
+use rocket::http::{ContentType, Status};
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::config::Config;
+use crate::models::ErrorResponse;
+use crate::services::{InvoiceError, InvoiceService};
+use tracing::info;
+
+pub async fn invoice_service_init(pool: &PgPool, config: &Config) -> InvoiceService {
+    InvoiceService::new(pool.clone(), config.business_details()).await
+}
+
+/// Renders a sale as a printable PDF invoice.
+#[get("/sales/invoice/<sales_id>")]
+pub async fn get_sales_invoice(invoice_service: &State<InvoiceService>, sales_id: i64) -> Result<(ContentType, Vec<u8>), (Status, Json<ErrorResponse>)> {
+    match invoice_service.get_invoice_pdf(sales_id).await {
+        Ok(pdf) => {
+            info!("Rendered invoice for sales record with ID: {}", sales_id);
+            Ok((ContentType::PDF, pdf))
+        }
+        Err(InvoiceError::NotFound) => {
+            info!("Sales record with ID {} not found.", sales_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Sales record not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error rendering invoice for sales record with ID {}: {:?}", sales_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to render invoice".to_string(),
+            })))
+        }
+    }
+}
@@ -5,7 +5,23 @@ mod customer_controller;
 mod debt_controller;
 mod sales_pair_controller;
 mod sales_controller;
+mod sales_return_controller;
+mod invoice_controller;
+mod price_list_controller;
+mod promotion_controller;
+mod tax_controller;
+mod quotation_controller;
+mod idempotency_controller;
+mod sync_controller;
 mod stock_controller;
+mod stock_transfer_controller;
+mod reorder_controller;
+mod valuation_controller;
+mod stocktake_controller;
+mod supplier_controller;
+mod purchase_order_controller;
+mod stock_lot_controller;
+mod unit_controller;
 mod downloads_controller;
 
 pub use store_controller::*;
@@ -14,6 +30,22 @@ pub use customer_controller::*;
 pub use debt_controller::*;
 pub use product_controller::*;
 pub use sales_controller::*;
+pub use sales_return_controller::*;
+pub use invoice_controller::*;
+pub use price_list_controller::*;
+pub use promotion_controller::*;
+pub use tax_controller::*;
+pub use quotation_controller::*;
+pub use idempotency_controller::*;
+pub use sync_controller::*;
 pub use sales_pair_controller::*;
 pub use stock_controller::*;
+pub use stock_transfer_controller::*;
+pub use reorder_controller::*;
+pub use valuation_controller::*;
+pub use stocktake_controller::*;
+pub use supplier_controller::*;
+pub use purchase_order_controller::*;
+pub use stock_lot_controller::*;
+pub use unit_controller::*;
 pub use downloads_controller::*;
@@ -0,0 +1,163 @@
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::models::{CustomerPrice, CustomerTier, EffectivePrice, ErrorResponse, TierPrice};
+use crate::services::{PriceListError, PriceListService};
+use tracing::info;
+
+/// Initializes a `PriceListService` instance using the provided `PgPool`.
+pub async fn price_list_service_init(pool: &PgPool) -> PriceListService {
+    PriceListService::new(pool.clone()).await
+}
+
+/// Retrieves the tier prices of a product.
+#[get("/prices/tiers/<product_id>")]
+pub async fn get_tier_prices(price_list_service: &State<PriceListService>, product_id: i32) -> Result<Json<Vec<TierPrice>>, (Status, Json<ErrorResponse>)> {
+    match price_list_service.get_tier_prices(product_id).await {
+        Ok(prices) => {
+            info!("Retrieved tier prices of product {}.", product_id);
+            Ok(Json(prices))
+        }
+        Err(e) => {
+            info!("Error retrieving tier prices of product {}: {:?}", product_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve tier prices".to_string(),
+            })))
+        }
+    }
+}
+
+/// Sets the price of a product for a customer tier.
+#[put("/prices/tiers", data = "<tier_price>")]
+pub async fn set_tier_price(price_list_service: &State<PriceListService>, tier_price: Json<TierPrice>) -> Result<Json<TierPrice>, (Status, Json<ErrorResponse>)> {
+    match price_list_service.set_tier_price(tier_price.into_inner()).await {
+        Ok(stored) => {
+            info!("Set {:?} price of product {}.", stored.tier, stored.product_id);
+            Ok(Json(stored))
+        }
+        Err(e @ PriceListError::InvalidPrice) => {
+            info!("Rejected tier price: {:?}", e);
+            Err((Status::BadRequest, Json(ErrorResponse { message: e.to_string() })))
+        }
+        Err(e @ PriceListError::UnknownProduct(_)) => {
+            info!("Rejected tier price: {:?}", e);
+            Err((Status::NotFound, Json(ErrorResponse { message: e.to_string() })))
+        }
+        Err(e) => {
+            info!("Failed to set tier price: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to set tier price".to_string(),
+            })))
+        }
+    }
+}
+
+/// Removes the price of a product for a customer tier.
+#[delete("/prices/tiers/<product_id>?<tier>")]
+pub async fn delete_tier_price(price_list_service: &State<PriceListService>, product_id: i32, tier: CustomerTier) -> Result<Status, (Status, Json<ErrorResponse>)> {
+    match price_list_service.delete_tier_price(product_id, tier).await {
+        Ok(()) => {
+            info!("Removed {:?} price of product {}.", tier, product_id);
+            Ok(Status::NoContent)
+        }
+        Err(PriceListError::NotFound) => {
+            info!("No {:?} price set for product {}.", tier, product_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Tier price not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to remove tier price: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to remove tier price".to_string(),
+            })))
+        }
+    }
+}
+
+/// Retrieves the prices set for a single customer.
+#[get("/prices/customers/<customer_id>")]
+pub async fn get_customer_prices(price_list_service: &State<PriceListService>, customer_id: i32) -> Result<Json<Vec<CustomerPrice>>, (Status, Json<ErrorResponse>)> {
+    match price_list_service.get_customer_prices(customer_id).await {
+        Ok(prices) => {
+            info!("Retrieved prices of customer {}.", customer_id);
+            Ok(Json(prices))
+        }
+        Err(e) => {
+            info!("Error retrieving prices of customer {}: {:?}", customer_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve customer prices".to_string(),
+            })))
+        }
+    }
+}
+
+/// Sets a customer's own price for a product.
+#[put("/prices/customers", data = "<customer_price>")]
+pub async fn set_customer_price(price_list_service: &State<PriceListService>, customer_price: Json<CustomerPrice>) -> Result<Json<CustomerPrice>, (Status, Json<ErrorResponse>)> {
+    match price_list_service.set_customer_price(customer_price.into_inner()).await {
+        Ok(stored) => {
+            info!("Set price of product {} for customer {}.", stored.product_id, stored.customer_id);
+            Ok(Json(stored))
+        }
+        Err(e @ PriceListError::InvalidPrice) => {
+            info!("Rejected customer price: {:?}", e);
+            Err((Status::BadRequest, Json(ErrorResponse { message: e.to_string() })))
+        }
+        Err(e @ (PriceListError::UnknownProduct(_) | PriceListError::UnknownCustomer(_))) => {
+            info!("Rejected customer price: {:?}", e);
+            Err((Status::NotFound, Json(ErrorResponse { message: e.to_string() })))
+        }
+        Err(e) => {
+            info!("Failed to set customer price: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to set customer price".to_string(),
+            })))
+        }
+    }
+}
+
+/// Removes a customer's own price for a product.
+#[delete("/prices/customers/<customer_id>/<product_id>")]
+pub async fn delete_customer_price(price_list_service: &State<PriceListService>, customer_id: i32, product_id: i32) -> Result<Status, (Status, Json<ErrorResponse>)> {
+    match price_list_service.delete_customer_price(customer_id, product_id).await {
+        Ok(()) => {
+            info!("Removed price of product {} for customer {}.", product_id, customer_id);
+            Ok(Status::NoContent)
+        }
+        Err(PriceListError::NotFound) => {
+            info!("No price of product {} set for customer {}.", product_id, customer_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Customer price not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to remove customer price: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to remove customer price".to_string(),
+            })))
+        }
+    }
+}
+
+/// Previews the price a customer would pay for a product right now.
+#[get("/prices/effective/<customer_id>/<product_id>")]
+pub async fn get_effective_price(price_list_service: &State<PriceListService>, customer_id: i32, product_id: i32) -> Result<Json<EffectivePrice>, (Status, Json<ErrorResponse>)> {
+    match price_list_service.get_effective_price(customer_id, product_id).await {
+        Ok(effective) => {
+            info!("Customer {} pays {} for product {} ({:?}).", customer_id, effective.price, product_id, effective.source);
+            Ok(Json(effective))
+        }
+        Err(e @ (PriceListError::UnknownProduct(_) | PriceListError::UnknownCustomer(_))) => {
+            info!("Cannot work out price: {:?}", e);
+            Err((Status::NotFound, Json(ErrorResponse { message: e.to_string() })))
+        }
+        Err(e) => {
+            info!("Failed to work out price of product {} for customer {}: {:?}", product_id, customer_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to work out price".to_string(),
+            })))
+        }
+    }
+}
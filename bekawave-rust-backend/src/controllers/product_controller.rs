@@ -5,6 +5,7 @@ use rocket::State;
 use sqlx::PgPool;
 use crate::models::{ErrorResponse, Product};
 use crate::services::{ProductService, ProductError};
+use crate::controllers::Idempotent;
 use tracing::info;
 
 pub async fn product_service_init(pool: &PgPool) -> ProductService {
@@ -57,7 +58,7 @@ pub async fn get_product(product_service: &State<ProductService>, product_id: i3
 }
 
 #[post("/products/create", data = "<product>")]
-pub async fn create_product(product_service: &State<ProductService>, product: Json<Product>) -> Result<Created<Json<Product>>, (Status, Json<ErrorResponse>)> {
+pub async fn create_product(product_service: &State<ProductService>, product: Idempotent<Product>) -> Result<Created<Json<Product>>, (Status, Json<ErrorResponse>)> {
     match product_service.create_product(product.into_inner()).await {
         Ok(created_product) => {
             info!("Created product successfully with ID: {}", created_product.product_id);
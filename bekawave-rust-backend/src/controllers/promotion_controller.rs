@@ -0,0 +1,155 @@
+use rocket::http::Status;
+use rocket::response::status::Created;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::models::{ErrorResponse, Promotion, PromotionCost};
+use crate::services::{PromotionError, PromotionService};
+use crate::controllers::Idempotent;
+use tracing::info;
+
+/// Initializes a `PromotionService` instance using the provided `PgPool`.
+pub async fn promotion_service_init(pool: &PgPool) -> PromotionService {
+    PromotionService::new(pool.clone()).await
+}
+
+/// Retrieves all promotions.
+#[get("/promotions/get-all")]
+pub async fn get_all_promotions(promotion_service: &State<PromotionService>) -> Result<Json<Vec<Promotion>>, (Status, Json<ErrorResponse>)> {
+    match promotion_service.get_all_promotions().await {
+        Ok(promotions) => {
+            info!("Retrieved all promotions successfully.");
+            Ok(Json(promotions))
+        }
+        Err(e) => {
+            info!("Error retrieving promotions: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve promotions".to_string(),
+            })))
+        }
+    }
+}
+
+/// Retrieves a promotion by ID.
+#[get("/promotions/by-id/<promotion_id>")]
+pub async fn get_promotion(promotion_service: &State<PromotionService>, promotion_id: i64) -> Result<Json<Promotion>, (Status, Json<ErrorResponse>)> {
+    match promotion_service.get_promotion(promotion_id).await {
+        Ok(promotion) => {
+            info!("Retrieved promotion with ID: {}", promotion_id);
+            Ok(Json(promotion))
+        }
+        Err(PromotionError::NotFound) => {
+            info!("Promotion with ID {} not found.", promotion_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Promotion not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error retrieving promotion with ID {}: {:?}", promotion_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve promotion".to_string(),
+            })))
+        }
+    }
+}
+
+/// Creates a promotion.
+#[post("/promotions/create", data = "<promotion>")]
+pub async fn create_promotion(promotion_service: &State<PromotionService>, promotion: Idempotent<Promotion>) -> Result<Created<Json<Promotion>>, (Status, Json<ErrorResponse>)> {
+    match promotion_service.create_promotion(promotion.into_inner()).await {
+        Ok(created) => {
+            info!("Created promotion with ID: {}", created.promotion_id);
+            Ok(Created::new("/promotions/get-all").body(Json(created)))
+        }
+        Err(e @ PromotionError::InvalidPromotion(_)) => {
+            info!("Promotion creation failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to create promotion: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to create promotion".to_string(),
+            })))
+        }
+    }
+}
+
+/// Updates a promotion.
+#[put("/promotions/update", data = "<promotion>")]
+pub async fn update_promotion(promotion_service: &State<PromotionService>, promotion: Json<Promotion>) -> Result<Json<Promotion>, (Status, Json<ErrorResponse>)> {
+    match promotion_service.update_promotion(promotion.into_inner()).await {
+        Ok(Some(updated)) => {
+            info!("Updated promotion with ID: {}", updated.promotion_id);
+            Ok(Json(updated))
+        }
+        Ok(None) => Err((Status::NotFound, Json(ErrorResponse {
+            message: "Promotion not found".to_string(),
+        }))),
+        Err(e @ PromotionError::InvalidPromotion(_)) => {
+            info!("Promotion update failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to update promotion: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to update promotion".to_string(),
+            })))
+        }
+    }
+}
+
+/// Deletes a promotion that has never discounted a sale.
+#[delete("/promotions/delete/<promotion_id>")]
+pub async fn delete_promotion(promotion_service: &State<PromotionService>, promotion_id: i64) -> Result<Status, (Status, Json<ErrorResponse>)> {
+    match promotion_service.delete_promotion(promotion_id).await {
+        Ok(()) => {
+            info!("Deleted promotion with ID: {}", promotion_id);
+            Ok(Status::NoContent)
+        }
+        Err(PromotionError::NotFound) => {
+            info!("Promotion with ID {} not found.", promotion_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Promotion not found".to_string(),
+            })))
+        }
+        Err(e @ PromotionError::InUse) => {
+            info!("Promotion with ID {} not deleted: {}", promotion_id, e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to delete promotion with ID {}: {:?}", promotion_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to delete promotion".to_string(),
+            })))
+        }
+    }
+}
+
+/// Reports the discounts each promotion has given on the sales made between `from` and `to`.
+#[get("/promotions/report?<from>&<to>")]
+pub async fn get_promotion_costs(promotion_service: &State<PromotionService>, from: Option<String>, to: Option<String>) -> Result<Json<Vec<PromotionCost>>, (Status, Json<ErrorResponse>)> {
+    match promotion_service.get_promotion_costs(from.as_deref(), to.as_deref()).await {
+        Ok(costs) => {
+            info!("Reported costs of {} promotions.", costs.len());
+            Ok(Json(costs))
+        }
+        Err(e @ PromotionError::InvalidQuery(_)) => {
+            info!("Promotion report failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to report promotion costs: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to report promotion costs".to_string(),
+            })))
+        }
+    }
+}
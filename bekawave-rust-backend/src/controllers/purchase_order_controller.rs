@@ -0,0 +1,212 @@
+use rocket::http::Status;
+use rocket::response::status::Created;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::models::{ErrorResponse, GoodsReceivedNote, NewGoodsReceivedNote, PurchaseOrder, PurchaseOrderFulfilment, PurchaseOrderStatus};
+use crate::services::{PurchaseOrderError, PurchaseOrderService};
+use crate::controllers::Idempotent;
+use tracing::info;
+
+/// Initializes a `PurchaseOrderService` instance using the provided `PgPool`.
+pub async fn purchase_order_service_init(pool: &PgPool) -> PurchaseOrderService {
+    PurchaseOrderService::new(pool.clone()).await
+}
+
+#[get("/purchase-orders/get-all?<status>&<supplier_id>&<store_id>")]
+pub async fn get_all_purchase_orders(purchase_order_service: &State<PurchaseOrderService>, status: Option<PurchaseOrderStatus>, supplier_id: Option<i32>, store_id: Option<i32>) -> Result<Json<Vec<PurchaseOrder>>, (Status, Json<ErrorResponse>)> {
+    match purchase_order_service.get_all_purchase_orders(status, supplier_id, store_id).await {
+        Ok(orders) => {
+            info!("Retrieved {} purchase orders.", orders.len());
+            Ok(Json(orders))
+        }
+        Err(e) => {
+            info!("Error retrieving purchase orders: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve purchase orders".to_string(),
+            })))
+        }
+    }
+}
+
+#[get("/purchase-orders/by-id/<purchase_order_id>")]
+pub async fn get_purchase_order(purchase_order_service: &State<PurchaseOrderService>, purchase_order_id: i64) -> Result<Json<PurchaseOrder>, (Status, Json<ErrorResponse>)> {
+    match purchase_order_service.get_purchase_order(purchase_order_id).await {
+        Ok(order) => {
+            info!("Successfully retrieved purchase order with ID: {}", purchase_order_id);
+            Ok(Json(order))
+        }
+        Err(PurchaseOrderError::NotFound) => {
+            info!("Purchase order with ID {} not found.", purchase_order_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Purchase order not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error retrieving purchase order with ID {}: {:?}", purchase_order_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve purchase order".to_string(),
+            })))
+        }
+    }
+}
+
+#[post("/purchase-orders/create", data = "<order>")]
+pub async fn create_purchase_order(purchase_order_service: &State<PurchaseOrderService>, order: Idempotent<PurchaseOrder>) -> Result<Created<Json<PurchaseOrder>>, (Status, Json<ErrorResponse>)> {
+    match purchase_order_service.create_purchase_order(order.into_inner()).await {
+        Ok(placed) => {
+            info!("Placed purchase order with ID: {}", placed.purchase_order_id);
+            Ok(Created::new(format!("/purchase-orders/by-id/{}", placed.purchase_order_id)).body(Json(placed)))
+        }
+        Err(e @ (PurchaseOrderError::EmptyOrder | PurchaseOrderError::UnknownSupplier(_) | PurchaseOrderError::UnknownStore(_) | PurchaseOrderError::InvalidLine(_))) => {
+            info!("Placing purchase order failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to place purchase order: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to place purchase order".to_string(),
+            })))
+        }
+    }
+}
+
+/// Takes a delivery against a purchase order into the order's store with a goods-received note.
+#[post("/purchase-orders/receive/<purchase_order_id>", data = "<receipt>")]
+pub async fn receive_purchase_order(purchase_order_service: &State<PurchaseOrderService>, purchase_order_id: i64, receipt: Idempotent<NewGoodsReceivedNote>) -> Result<Created<Json<GoodsReceivedNote>>, (Status, Json<ErrorResponse>)> {
+    match purchase_order_service.receive_goods(purchase_order_id, receipt.into_inner()).await {
+        Ok(note) => {
+            info!("Recorded goods-received note {} against purchase order {}", note.goods_received_note_id, purchase_order_id);
+            Ok(Created::new(format!("/purchase-orders/goods-received/{}", purchase_order_id)).body(Json(note)))
+        }
+        Err(PurchaseOrderError::NotFound) => {
+            info!("Purchase order with ID {} not found for receipt.", purchase_order_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Purchase order not found".to_string(),
+            })))
+        }
+        Err(e @ PurchaseOrderError::InvalidReceipt(_)) => {
+            info!("Receiving purchase order failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e @ PurchaseOrderError::InvalidStatus { .. }) => {
+            info!("Receiving purchase order failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to receive purchase order {}: {:?}", purchase_order_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to receive purchase order".to_string(),
+            })))
+        }
+    }
+}
+
+#[get("/purchase-orders/goods-received/<purchase_order_id>")]
+pub async fn get_goods_received_notes(purchase_order_service: &State<PurchaseOrderService>, purchase_order_id: i64) -> Result<Json<Vec<GoodsReceivedNote>>, (Status, Json<ErrorResponse>)> {
+    match purchase_order_service.get_goods_received_notes(purchase_order_id).await {
+        Ok(notes) => {
+            info!("Retrieved {} goods-received notes of purchase order {}", notes.len(), purchase_order_id);
+            Ok(Json(notes))
+        }
+        Err(PurchaseOrderError::NotFound) => {
+            info!("Purchase order with ID {} not found.", purchase_order_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Purchase order not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error retrieving goods-received notes of purchase order {}: {:?}", purchase_order_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve goods-received notes".to_string(),
+            })))
+        }
+    }
+}
+
+/// Reports the partial and excess deliveries against a purchase order, line by line.
+#[get("/purchase-orders/fulfilment/<purchase_order_id>")]
+pub async fn get_purchase_order_fulfilment(purchase_order_service: &State<PurchaseOrderService>, purchase_order_id: i64) -> Result<Json<PurchaseOrderFulfilment>, (Status, Json<ErrorResponse>)> {
+    match purchase_order_service.get_fulfilment(purchase_order_id).await {
+        Ok(fulfilment) => {
+            info!("Reported the fulfilment of purchase order with ID: {}", purchase_order_id);
+            Ok(Json(fulfilment))
+        }
+        Err(PurchaseOrderError::NotFound) => {
+            info!("Purchase order with ID {} not found.", purchase_order_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Purchase order not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error reporting the fulfilment of purchase order {}: {:?}", purchase_order_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to report purchase order fulfilment".to_string(),
+            })))
+        }
+    }
+}
+
+/// Closes a partially received purchase order short.
+#[post("/purchase-orders/close/<purchase_order_id>")]
+pub async fn close_purchase_order(purchase_order_service: &State<PurchaseOrderService>, purchase_order_id: i64) -> Result<Json<PurchaseOrder>, (Status, Json<ErrorResponse>)> {
+    match purchase_order_service.close_purchase_order(purchase_order_id).await {
+        Ok(order) => {
+            info!("Closed purchase order with ID: {}", purchase_order_id);
+            Ok(Json(order))
+        }
+        Err(PurchaseOrderError::NotFound) => {
+            info!("Purchase order with ID {} not found for closing.", purchase_order_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Purchase order not found".to_string(),
+            })))
+        }
+        Err(e @ PurchaseOrderError::InvalidStatus { .. }) => {
+            info!("Closing purchase order failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to close purchase order {}: {:?}", purchase_order_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to close purchase order".to_string(),
+            })))
+        }
+    }
+}
+
+/// Calls off a purchase order nothing has been received against.
+#[post("/purchase-orders/cancel/<purchase_order_id>")]
+pub async fn cancel_purchase_order(purchase_order_service: &State<PurchaseOrderService>, purchase_order_id: i64) -> Result<Json<PurchaseOrder>, (Status, Json<ErrorResponse>)> {
+    match purchase_order_service.cancel_purchase_order(purchase_order_id).await {
+        Ok(order) => {
+            info!("Cancelled purchase order with ID: {}", purchase_order_id);
+            Ok(Json(order))
+        }
+        Err(PurchaseOrderError::NotFound) => {
+            info!("Purchase order with ID {} not found for cancellation.", purchase_order_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Purchase order not found".to_string(),
+            })))
+        }
+        Err(e @ PurchaseOrderError::InvalidStatus { .. }) => {
+            info!("Cancelling purchase order failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to cancel purchase order {}: {:?}", purchase_order_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to cancel purchase order".to_string(),
+            })))
+        }
+    }
+}
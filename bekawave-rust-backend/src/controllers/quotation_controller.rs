@@ -0,0 +1,219 @@
+use rocket::http::Status;
+use rocket::response::status::Created;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::models::{ErrorResponse, Quotation, QuotationConversion, QuotationStatus, QuotationStatusChange, Sales};
+use crate::services::{QuotationError, QuotationService, SalesError, SalesService};
+use crate::controllers::Idempotent;
+use tracing::info;
+
+/// Initializes a `QuotationService` instance using the provided `PgPool`.
+pub async fn quotation_service_init(pool: &PgPool) -> QuotationService {
+    QuotationService::new(pool.clone()).await
+}
+
+#[get("/quotations/get-all?<status>&<customer_id>")]
+pub async fn get_all_quotations(quotation_service: &State<QuotationService>, status: Option<QuotationStatus>, customer_id: Option<i32>) -> Result<Json<Vec<Quotation>>, (Status, Json<ErrorResponse>)> {
+    match quotation_service.get_all_quotations(status, customer_id).await {
+        Ok(quotations) => {
+            info!("Retrieved {} quotations.", quotations.len());
+            Ok(Json(quotations))
+        }
+        Err(e) => {
+            info!("Error retrieving quotations: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve quotations".to_string(),
+            })))
+        }
+    }
+}
+
+#[get("/quotations/by-id/<quotation_id>")]
+pub async fn get_quotation(quotation_service: &State<QuotationService>, quotation_id: i64) -> Result<Json<Quotation>, (Status, Json<ErrorResponse>)> {
+    match quotation_service.get_quotation(quotation_id).await {
+        Ok(quotation) => {
+            info!("Successfully retrieved quotation with ID: {}", quotation_id);
+            Ok(Json(quotation))
+        }
+        Err(QuotationError::NotFound) => {
+            info!("Quotation with ID {} not found.", quotation_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Quotation not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error retrieving quotation with ID {}: {:?}", quotation_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve quotation".to_string(),
+            })))
+        }
+    }
+}
+
+#[post("/quotations/create", data = "<quotation>")]
+pub async fn create_quotation(quotation_service: &State<QuotationService>, sales_service: &State<SalesService>, quotation: Idempotent<Quotation>) -> Result<Created<Json<Quotation>>, (Status, Json<ErrorResponse>)> {
+    match quotation_service.create_quotation(sales_service, quotation.into_inner()).await {
+        Ok(created) => {
+            info!("Created quotation successfully with ID: {}", created.quotation_id);
+            Ok(Created::new(format!("/quotations/by-id/{}", created.quotation_id)).body(Json(created)))
+        }
+        Err(e @ (QuotationError::EmptyQuotation | QuotationError::InvalidValidity)) => {
+            info!("Quotation creation failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(QuotationError::SalesError(e @ (SalesError::UnknownStore(_) | SalesError::UnknownCustomer(_) | SalesError::InvalidLine(_)))) => {
+            info!("Quotation creation failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to create quotation: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to create quotation".to_string(),
+            })))
+        }
+    }
+}
+
+#[put("/quotations/update", data = "<quotation>")]
+pub async fn update_quotation(quotation_service: &State<QuotationService>, sales_service: &State<SalesService>, quotation: Json<Quotation>) -> Result<Json<Quotation>, (Status, Json<ErrorResponse>)> {
+    let quotation_id = quotation.quotation_id;
+    match quotation_service.update_quotation(sales_service, quotation.into_inner()).await {
+        Ok(Some(updated)) => {
+            info!("Updated quotation with ID: {}", quotation_id);
+            Ok(Json(updated))
+        }
+        Ok(None) => {
+            info!("Quotation with ID {} not found for update.", quotation_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Quotation not found".to_string(),
+            })))
+        }
+        Err(e @ (QuotationError::EmptyQuotation | QuotationError::InvalidValidity)) => {
+            info!("Quotation update failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(QuotationError::SalesError(e @ (SalesError::UnknownStore(_) | SalesError::UnknownCustomer(_) | SalesError::InvalidLine(_)))) => {
+            info!("Quotation update failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e @ QuotationError::NotEditable(_)) => {
+            info!("Quotation update failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error updating quotation with ID {}: {:?}", quotation_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to update quotation".to_string(),
+            })))
+        }
+    }
+}
+
+/// Sends, accepts or rejects a quotation.
+#[put("/quotations/status/<quotation_id>", data = "<change>")]
+pub async fn set_quotation_status(quotation_service: &State<QuotationService>, quotation_id: i64, change: Json<QuotationStatusChange>) -> Result<Json<Quotation>, (Status, Json<ErrorResponse>)> {
+    match quotation_service.set_quotation_status(quotation_id, change.into_inner()).await {
+        Ok(quotation) => {
+            info!("Quotation {} is now {:?}", quotation_id, quotation.status);
+            Ok(Json(quotation))
+        }
+        Err(QuotationError::NotFound) => {
+            info!("Quotation with ID {} not found for status change.", quotation_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Quotation not found".to_string(),
+            })))
+        }
+        Err(e @ (QuotationError::InvalidStatus { .. } | QuotationError::AlreadyConverted(_))) => {
+            info!("Quotation status change failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error changing the status of quotation {}: {:?}", quotation_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to change quotation status".to_string(),
+            })))
+        }
+    }
+}
+
+#[delete("/quotations/delete/<quotation_id>")]
+pub async fn delete_quotation(quotation_service: &State<QuotationService>, quotation_id: i64) -> Result<Status, (Status, Json<ErrorResponse>)> {
+    match quotation_service.delete_quotation(quotation_id).await {
+        Ok(()) => {
+            info!("Deleted quotation with ID: {}", quotation_id);
+            Ok(Status::NoContent)
+        }
+        Err(QuotationError::NotFound) => {
+            info!("Quotation with ID {} not found for deletion.", quotation_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Quotation not found".to_string(),
+            })))
+        }
+        Err(e @ QuotationError::AlreadyConverted(_)) => {
+            info!("Quotation deletion failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error deleting quotation with ID {}: {:?}", quotation_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to delete quotation".to_string(),
+            })))
+        }
+    }
+}
+
+/// Turns an accepted quotation into a sale, checking stock and prices again.
+#[post("/quotations/convert/<quotation_id>", data = "<conversion>")]
+pub async fn convert_quotation(quotation_service: &State<QuotationService>, sales_service: &State<SalesService>, quotation_id: i64, conversion: Idempotent<QuotationConversion>) -> Result<Created<Json<Sales>>, (Status, Json<ErrorResponse>)> {
+    match quotation_service.convert_quotation(sales_service, quotation_id, conversion.into_inner()).await {
+        Ok(sales) => {
+            info!("Converted quotation {} into sale {}", quotation_id, sales.sales_id);
+            Ok(Created::new(format!("/sales/get_sales_by_id/{}", sales.sales_id)).body(Json(sales)))
+        }
+        Err(QuotationError::NotFound) => {
+            info!("Quotation with ID {} not found for conversion.", quotation_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Quotation not found".to_string(),
+            })))
+        }
+        Err(e @ (QuotationError::NotAccepted(_) | QuotationError::AlreadyConverted(_) | QuotationError::PricesChanged { .. })) => {
+            info!("Quotation conversion failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(QuotationError::SalesError(e @ SalesError::InsufficientStock { .. })) => {
+            info!("Quotation conversion failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(QuotationError::SalesError(e @ (SalesError::UnknownCustomer(_) | SalesError::UnknownStore(_) | SalesError::InvalidLine(_) | SalesError::InvalidPayment(_)))) => {
+            info!("Quotation conversion failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to convert quotation {}: {:?}", quotation_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to convert quotation".to_string(),
+            })))
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use rocket::http::Status;
+use rocket::response::stream::{Event, EventStream};
+use rocket::serde::json::Json;
+use rocket::{Shutdown, State};
+use sqlx::PgPool;
+use crate::models::{ErrorResponse, LowStock, ReorderLevel, StockAlert, STOCK_ALERT_CHANNEL};
+use crate::services::{ReorderError, ReorderService};
+use tracing::info;
+
+/// Initializes a `ReorderService` instance using the provided `PgPool`.
+pub async fn reorder_service_init(pool: &PgPool) -> ReorderService {
+    ReorderService::new(pool.clone()).await
+}
+
+#[get("/stocks/reorder-levels?<store_id>")]
+pub async fn get_reorder_levels(reorder_service: &State<ReorderService>, store_id: Option<i32>) -> Result<Json<Vec<ReorderLevel>>, (Status, Json<ErrorResponse>)> {
+    match reorder_service.get_reorder_levels(store_id).await {
+        Ok(levels) => {
+            info!("Retrieved {} reorder levels.", levels.len());
+            Ok(Json(levels))
+        }
+        Err(e) => {
+            info!("Error retrieving reorder levels: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve reorder levels".to_string(),
+            })))
+        }
+    }
+}
+
+/// Sets the reorder level and quantity of a product in a store.
+#[put("/stocks/reorder-levels", data = "<level>")]
+pub async fn set_reorder_level(reorder_service: &State<ReorderService>, level: Json<ReorderLevel>) -> Result<Json<ReorderLevel>, (Status, Json<ErrorResponse>)> {
+    match reorder_service.set_reorder_level(level.into_inner()).await {
+        Ok(stored) => {
+            info!("Set the reorder level of product {} in store {}", stored.product_id, stored.store_id);
+            Ok(Json(stored))
+        }
+        Err(e @ ReorderError::InvalidLevel(_)) => {
+            info!("Setting reorder level failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to set reorder level: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to set reorder level".to_string(),
+            })))
+        }
+    }
+}
+
+#[delete("/stocks/reorder-levels/<store_id>/<product_id>")]
+pub async fn delete_reorder_level(reorder_service: &State<ReorderService>, store_id: i32, product_id: i32) -> Result<Status, (Status, Json<ErrorResponse>)> {
+    match reorder_service.delete_reorder_level(store_id, product_id).await {
+        Ok(()) => {
+            info!("Deleted the reorder level of product {} in store {}", product_id, store_id);
+            Ok(Status::NoContent)
+        }
+        Err(ReorderError::NotFound) => {
+            info!("No reorder level for product {} in store {}.", product_id, store_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Reorder level not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error deleting reorder level: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to delete reorder level".to_string(),
+            })))
+        }
+    }
+}
+
+/// Lists the stock below its reorder level, with suggested order quantities.
+#[get("/stocks/low?<store_id>")]
+pub async fn get_low_stock(reorder_service: &State<ReorderService>, store_id: Option<i32>) -> Result<Json<Vec<LowStock>>, (Status, Json<ErrorResponse>)> {
+    match reorder_service.get_low_stock(store_id).await {
+        Ok(low_stock) => {
+            info!("Found {} products below their reorder level.", low_stock.len());
+            Ok(Json(low_stock))
+        }
+        Err(e) => {
+            info!("Error listing low stock: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to list low stock".to_string(),
+            })))
+        }
+    }
+}
+
+#[get("/stocks/alerts?<store_id>&<acknowledged>")]
+pub async fn get_stock_alerts(reorder_service: &State<ReorderService>, store_id: Option<i32>, acknowledged: Option<bool>) -> Result<Json<Vec<StockAlert>>, (Status, Json<ErrorResponse>)> {
+    match reorder_service.get_stock_alerts(store_id, acknowledged).await {
+        Ok(alerts) => {
+            info!("Retrieved {} stock alerts.", alerts.len());
+            Ok(Json(alerts))
+        }
+        Err(e) => {
+            info!("Error retrieving stock alerts: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve stock alerts".to_string(),
+            })))
+        }
+    }
+}
+
+#[put("/stocks/alerts/acknowledge/<alert_id>")]
+pub async fn acknowledge_stock_alert(reorder_service: &State<ReorderService>, alert_id: i64) -> Result<Json<StockAlert>, (Status, Json<ErrorResponse>)> {
+    match reorder_service.acknowledge_stock_alert(alert_id).await {
+        Ok(alert) => {
+            info!("Acknowledged stock alert with ID: {}", alert_id);
+            Ok(Json(alert))
+        }
+        Err(ReorderError::AlertNotFound) => {
+            info!("Stock alert with ID {} not found.", alert_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Stock alert not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error acknowledging stock alert {}: {:?}", alert_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to acknowledge stock alert".to_string(),
+            })))
+        }
+    }
+}
+
+/// Streams stock alerts as server-sent events as they are raised, each a `stock_alert` event with the alert as JSON.
+#[get("/stocks/alerts/stream")]
+pub async fn stream_stock_alerts(reorder_service: &State<ReorderService>, mut shutdown: Shutdown) -> Result<EventStream![], (Status, Json<ErrorResponse>)> {
+    let mut listener = match reorder_service.listen_for_alerts().await {
+        Ok(listener) => listener,
+        Err(e) => {
+            info!("Error listening for stock alerts: {:?}", e);
+            return Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to listen for stock alerts".to_string(),
+            })));
+        }
+    };
+
+    Ok(EventStream! {
+        loop {
+            let notification = rocket::tokio::select! {
+                notification = listener.recv() => notification,
+                _ = &mut shutdown => break,
+            };
+            match notification {
+                Ok(notification) => yield Event::data(notification.payload().to_string()).event(STOCK_ALERT_CHANNEL),
+                Err(e) => {
+                    info!("Stopped streaming stock alerts: {:?}", e);
+                    break;
+                }
+            }
+        }
+    })
+}
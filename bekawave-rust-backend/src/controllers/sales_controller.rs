@@ -3,27 +3,28 @@ use rocket::response::status::Created;
 use rocket::serde::json::Json;
 use rocket::State;
 use sqlx::PgPool;
-use crate::models::{ErrorResponse, Sales};
+use crate::config::Config;
+use crate::models::{ErrorResponse, Sales, SalesPage, SalesQuery, SalesVoid};
 use crate::services::{SalesService, SalesError};
+use crate::controllers::Idempotent;
 use tracing::info;
 
-pub async fn sales_service_init(pool: &PgPool) -> SalesService {
-    SalesService::new(pool.clone()).await
+pub async fn sales_service_init(pool: &PgPool, config: &Config) -> SalesService {
+    SalesService::new(pool.clone(), config.allow_negative_stock, config.tax_settings()).await
 }
 
-#[get("/sales/get-all")]
-pub async fn get_all_sales(sales_service: &State<SalesService>) -> Result<Json<Vec<Sales>>, (Status, Json<ErrorResponse>)> {
-    match sales_service.get_all_sales().await {
-        Ok(sales) => {
-            if sales.is_empty() {
-                info!("No sales records found.");
-                Err((Status::NotFound, Json(ErrorResponse {
-                    message: "No sales records found".to_string(),
-                })))
-            } else {
-                info!("Retrieved all sales records successfully.");
-                Ok(Json(sales))
-            }
+#[get("/sales/get-all?<query..>")]
+pub async fn get_all_sales(sales_service: &State<SalesService>, query: SalesQuery) -> Result<Json<SalesPage>, (Status, Json<ErrorResponse>)> {
+    match sales_service.search_sales(&query).await {
+        Ok(page) => {
+            info!("Retrieved {} of {} sales records.", page.sales.len(), page.total_count);
+            Ok(Json(page))
+        }
+        Err(e @ SalesError::InvalidQuery(_)) => {
+            info!("Invalid sales query: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
         }
         Err(e) => {
             info!("Error retrieving sales records: {:?}", e);
@@ -34,6 +35,28 @@ pub async fn get_all_sales(sales_service: &State<SalesService>) -> Result<Json<V
     }
 }
 
+#[get("/sales/by-invoice/<invoice_number>")]
+pub async fn get_sales_by_invoice_number(sales_service: &State<SalesService>, invoice_number: &str) -> Result<Json<Sales>, (Status, Json<ErrorResponse>)> {
+    match sales_service.get_sales_by_invoice_number(invoice_number).await {
+        Ok(sales) => {
+            info!("Successfully retrieved sales record with invoice number: {}", invoice_number);
+            Ok(Json(sales))
+        }
+        Err(SalesError::NotFound) => {
+            info!("Sales record with invoice number {} not found.", invoice_number);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Sales record not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error retrieving sales record with invoice number {}: {:?}", invoice_number, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve sales record".to_string(),
+            })))
+        }
+    }
+}
+
 #[get("/sales/get_sales_by_id/<sales_id>")]
 pub async fn get_sales(sales_service: &State<SalesService>, sales_id: i32) -> Result<Json<Sales>, (Status, Json<ErrorResponse>)> {
     match sales_service.get_sales(sales_id).await {
@@ -57,12 +80,48 @@ pub async fn get_sales(sales_service: &State<SalesService>, sales_id: i32) -> Re
 }
 
 #[post("/sales/create", data = "<sales>")]
-pub async fn create_sales(sales_service: &State<SalesService>, sales: Json<Sales>) -> Result<Created<Json<Sales>>, (Status, Json<ErrorResponse>)> {
+pub async fn create_sales(sales_service: &State<SalesService>, sales: Idempotent<Sales>) -> Result<Created<Json<Sales>>, (Status, Json<ErrorResponse>)> {
     match sales_service.create_sales(sales.into_inner()).await {
         Ok(created_sales) => {
             info!("Created sales record successfully with ID: {}", created_sales.sales_id);
             Ok(Created::new("/sales/get-all").body(Json(created_sales)))
         }
+        Err(SalesError::EmptyOrder) => {
+            info!("Sales order creation failed: no line items");
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: "Sales order must have at least one line item".to_string(),
+            })))
+        }
+        Err(e @ (SalesError::UnknownStore(_) | SalesError::UnknownCustomer(_))) => {
+            info!("Sales order creation failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e @ SalesError::InsufficientStock { .. }) => {
+            info!("Sales order creation failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e @ SalesError::InvalidLine(_)) => {
+            info!("Sales order creation failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e @ SalesError::InvalidPayment(_)) => {
+            info!("Sales order creation failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e @ SalesError::PriceMismatch { .. }) => {
+            info!("Sales order creation failed: {}", e);
+            Err((Status::UnprocessableEntity, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
         Err(e) => {
             info!("Failed to create sales record: {:?}", e);
             Err((Status::InternalServerError, Json(ErrorResponse {
@@ -72,6 +131,35 @@ pub async fn create_sales(sales_service: &State<SalesService>, sales: Json<Sales
     }
 }
 
+/// Prices a sales order, promotions included, without recording it.
+#[post("/sales/preview", data = "<sales>")]
+pub async fn preview_sales(sales_service: &State<SalesService>, sales: Json<Sales>) -> Result<Json<Sales>, (Status, Json<ErrorResponse>)> {
+    match sales_service.preview_sales(sales.into_inner()).await {
+        Ok(priced_sales) => {
+            info!("Priced sales order at {}", priced_sales.total_price);
+            Ok(Json(priced_sales))
+        }
+        Err(SalesError::EmptyOrder) => {
+            info!("Sales order preview failed: no line items");
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: "Sales order must have at least one line item".to_string(),
+            })))
+        }
+        Err(e @ (SalesError::UnknownCustomer(_) | SalesError::InvalidLine(_))) => {
+            info!("Sales order preview failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to price sales order: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to price sales order".to_string(),
+            })))
+        }
+    }
+}
+
 #[put("/sales/update", data = "<sales>")]
 pub async fn update_sales(sales_service: &State<SalesService>, sales: Json<Sales>) -> Result<Json<Sales>, (Status, Json<ErrorResponse>)> {
     match sales_service.update_sales(sales.into_inner()).await {
@@ -85,6 +173,66 @@ pub async fn update_sales(sales_service: &State<SalesService>, sales: Json<Sales
                 message: "Sales record not found".to_string(),
             })))
         }
+        Err(SalesError::EmptyOrder) => {
+            info!("Sales order update failed: no line items");
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: "Sales order must have at least one line item".to_string(),
+            })))
+        }
+        Err(e @ (SalesError::UnknownStore(_) | SalesError::UnknownCustomer(_))) => {
+            info!("Sales order update failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e @ SalesError::InsufficientStock { .. }) => {
+            info!("Sales order update failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(SalesError::AlreadyVoided) => {
+            info!("Sales order update failed: order was voided");
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: "Sales record has been voided".to_string(),
+            })))
+        }
+        Err(SalesError::DebtSettled) => {
+            info!("Sales order update failed: its debt was already paid");
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: "Sales record's debt has already been paid".to_string(),
+            })))
+        }
+        Err(e @ SalesError::InvoiceNumbered(_)) => {
+            info!("Sales order update failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(SalesError::HasReturns) => {
+            info!("Sales order update failed: returns recorded against it");
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: "Sales record has returns recorded against it".to_string(),
+            })))
+        }
+        Err(e @ SalesError::InvalidLine(_)) => {
+            info!("Sales order update failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e @ SalesError::InvalidPayment(_)) => {
+            info!("Sales order update failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e @ SalesError::PriceMismatch { .. }) => {
+            info!("Sales order update failed: {}", e);
+            Err((Status::UnprocessableEntity, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
         Err(e) => {
             info!("Failed to update sales record with Error {:?}", e);
             Err((Status::InternalServerError, Json(ErrorResponse {
@@ -94,24 +242,57 @@ pub async fn update_sales(sales_service: &State<SalesService>, sales: Json<Sales
     }
 }
 
-#[delete("/sales/delete/<sales_id>")]
-pub async fn delete_sales(sales_service: &State<SalesService>, sales_id: i32) -> Result<Json<bool>, (Status, Json<ErrorResponse>)> {
-    match sales_service.delete_sales(sales_id).await {
-        Ok(was_deleted) => {
-            if was_deleted {
-                info!("Successfully deleted sales record with ID: {}", sales_id);
-                Ok(Json(true))
-            } else {
-                info!("No sales record found with ID: {} for deletion.", sales_id);
-                Err((Status::NotFound, Json(ErrorResponse {
-                    message: "Sales record not found".to_string(),
-                })))
-            }
+#[get("/sales/voided/get-all")]
+pub async fn get_voided_sales(sales_service: &State<SalesService>) -> Result<Json<Vec<Sales>>, (Status, Json<ErrorResponse>)> {
+    match sales_service.get_voided_sales().await {
+        Ok(sales) => {
+            info!("Retrieved {} voided sales records.", sales.len());
+            Ok(Json(sales))
+        }
+        Err(e) => {
+            info!("Error retrieving voided sales records: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve voided sales records".to_string(),
+            })))
+        }
+    }
+}
+
+#[put("/sales/void/<sales_id>", data = "<void>")]
+pub async fn void_sales(sales_service: &State<SalesService>, sales_id: i32, void: Json<SalesVoid>) -> Result<Json<Sales>, (Status, Json<ErrorResponse>)> {
+    match sales_service.void_sales(sales_id, void.into_inner()).await {
+        Ok(Some(voided_sales)) => {
+            info!("Successfully voided sales record with ID: {}", sales_id);
+            Ok(Json(voided_sales))
+        }
+        Ok(None) => {
+            info!("No sales record found with ID: {} to void.", sales_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Sales record not found".to_string(),
+            })))
+        }
+        Err(SalesError::AlreadyVoided) => {
+            info!("Sales record with ID {} was already voided.", sales_id);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: "Sales record has already been voided".to_string(),
+            })))
+        }
+        Err(SalesError::DebtSettled) => {
+            info!("Sales record with ID {} has a paid debt and cannot be voided.", sales_id);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: "Sales record's debt has already been paid".to_string(),
+            })))
+        }
+        Err(e @ SalesError::InvalidVoid) => {
+            info!("Voiding sales record with ID {} failed: {}", sales_id, e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
         }
         Err(e) => {
-            info!("Error deleting sales record with ID {}: {:?}", sales_id, e);
+            info!("Error voiding sales record with ID {}: {:?}", sales_id, e);
             Err((Status::InternalServerError, Json(ErrorResponse {
-                message: "Failed to delete sales record".to_string(),
+                message: "Failed to void sales record".to_string(),
             })))
         }
     }
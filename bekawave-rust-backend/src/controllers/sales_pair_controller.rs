@@ -5,6 +5,7 @@ use rocket::State;
 use sqlx::PgPool;
 use crate::models::{SalesPair, ErrorResponse};
 use crate::services::{SalesPairService, SalesPairError};
+use crate::controllers::Idempotent;
 use tracing::info;
 
 pub async fn sales_pair_service_init(pool: &PgPool) -> SalesPairService {
@@ -49,7 +50,7 @@ pub async fn get_sales_pair(sales_pair_service: &State<SalesPairService>, sales_
 }
 
 #[post("/sales_pairs/create", data = "<sales_pair>")]
-pub async fn create_sales_pair(sales_pair_service: &State<SalesPairService>, sales_pair: Json<SalesPair>) -> Result<Created<Json<SalesPair>>, (Status, Json<ErrorResponse>)> {
+pub async fn create_sales_pair(sales_pair_service: &State<SalesPairService>, sales_pair: Idempotent<SalesPair>) -> Result<Created<Json<SalesPair>>, (Status, Json<ErrorResponse>)> {
     match sales_pair_service.create_sales_pair(sales_pair.into_inner()).await {
         Ok(created_sales_pair) => {
             info!("Created sales pair successfully");
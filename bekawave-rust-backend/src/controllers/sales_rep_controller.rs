@@ -6,6 +6,7 @@ use sqlx::{PgPool};
 use crate::services::{SalesRepError, SalesRepService};
 use tracing::info;
 use crate::models::{ErrorResponse, SalesRep};
+use crate::controllers::Idempotent;
 
 
 pub async fn sales_rep_init(pool: &PgPool) -> SalesRepService {
@@ -52,7 +53,7 @@ pub async fn get_sales_rep(sales_rep_service: &State<SalesRepService>, id: i32)
 }
 
 #[post("/sales-reps/create-new", data = "<sales_rep>")]
-pub async fn create_sales_rep(sales_rep_service: &State<SalesRepService>, sales_rep: Json<SalesRep>) -> Result<Created<Json<SalesRep>>, (Status, Json<ErrorResponse>)> {
+pub async fn create_sales_rep(sales_rep_service: &State<SalesRepService>, sales_rep: Idempotent<SalesRep>) -> Result<Created<Json<SalesRep>>, (Status, Json<ErrorResponse>)> {
     match sales_rep_service.create_sales_rep(sales_rep.into_inner()).await {
         Ok(new_sales_rep) => {
             info!("Created sales rep successfully");
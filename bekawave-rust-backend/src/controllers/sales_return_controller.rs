@@ -0,0 +1,101 @@
+use rocket::http::Status;
+use rocket::response::status::Created;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::models::{ErrorResponse, SalesReturn};
+use crate::services::{SalesReturnError, SalesReturnService};
+use crate::controllers::Idempotent;
+use tracing::info;
+
+pub async fn sales_return_service_init(pool: &PgPool) -> SalesReturnService {
+    SalesReturnService::new(pool.clone()).await
+}
+
+#[get("/sales/returns/get-all")]
+pub async fn get_all_sales_returns(sales_return_service: &State<SalesReturnService>) -> Result<Json<Vec<SalesReturn>>, (Status, Json<ErrorResponse>)> {
+    match sales_return_service.get_all_returns().await {
+        Ok(returns) => {
+            info!("Retrieved {} sales returns.", returns.len());
+            Ok(Json(returns))
+        }
+        Err(e) => {
+            info!("Error retrieving sales returns: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve sales returns".to_string(),
+            })))
+        }
+    }
+}
+
+#[get("/sales/returns/by-id/<return_id>")]
+pub async fn get_sales_return(sales_return_service: &State<SalesReturnService>, return_id: i64) -> Result<Json<SalesReturn>, (Status, Json<ErrorResponse>)> {
+    match sales_return_service.get_return(return_id).await {
+        Ok(sales_return) => {
+            info!("Successfully retrieved sales return with ID: {}", return_id);
+            Ok(Json(sales_return))
+        }
+        Err(SalesReturnError::NotFound) => {
+            info!("Sales return with ID {} not found.", return_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Sales return not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error retrieving sales return with ID {}: {:?}", return_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve sales return".to_string(),
+            })))
+        }
+    }
+}
+
+#[get("/sales/returns/by-sale/<sales_id>")]
+pub async fn get_sales_returns_for_sale(sales_return_service: &State<SalesReturnService>, sales_id: i64) -> Result<Json<Vec<SalesReturn>>, (Status, Json<ErrorResponse>)> {
+    match sales_return_service.get_returns_for_sale(sales_id).await {
+        Ok(returns) => {
+            info!("Retrieved {} returns for sales record with ID: {}", returns.len(), sales_id);
+            Ok(Json(returns))
+        }
+        Err(e) => {
+            info!("Error retrieving returns for sales record with ID {}: {:?}", sales_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve sales returns".to_string(),
+            })))
+        }
+    }
+}
+
+#[post("/sales/returns/create", data = "<sales_return>")]
+pub async fn create_sales_return(sales_return_service: &State<SalesReturnService>, sales_return: Idempotent<SalesReturn>) -> Result<Created<Json<SalesReturn>>, (Status, Json<ErrorResponse>)> {
+    match sales_return_service.create_return(sales_return.into_inner()).await {
+        Ok(created_return) => {
+            info!("Recorded sales return with ID: {}", created_return.return_id);
+            Ok(Created::new("/sales/returns/get-all").body(Json(created_return)))
+        }
+        Err(SalesReturnError::SaleNotFound) => {
+            info!("Sales return failed: sale not found");
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Sales record not found".to_string(),
+            })))
+        }
+        Err(SalesReturnError::SaleVoided) => {
+            info!("Sales return failed: sale was voided");
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: "Sales record has been voided".to_string(),
+            })))
+        }
+        Err(e @ (SalesReturnError::EmptyReturn | SalesReturnError::InvalidLine(_))) => {
+            info!("Sales return failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to record sales return: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to record sales return".to_string(),
+            })))
+        }
+    }
+}
@@ -3,8 +3,9 @@ use rocket::response::status::Created;
 use rocket::serde::json::Json;
 use rocket::State;
 use sqlx::PgPool;
-use crate::models::{ErrorResponse, Stock};
+use crate::models::{ErrorResponse, NewStockMovement, Stock, StockMovement, StockMovementQuery};
 use crate::services::{StockService, StockError};
+use crate::controllers::Idempotent;
 use tracing::info;
 
 /// Initialize the StockService with the database pool.
@@ -50,7 +51,7 @@ pub async fn get_stock(stock_service: &State<StockService>, stock_id: i32) -> Re
 }
 
 #[post("/stocks/create", data = "<stock>")]
-pub async fn create_stock(stock_service: &State<StockService>, stock: Json<Stock>) -> Result<Created<Json<Stock>>, (Status, Json<ErrorResponse>)> {
+pub async fn create_stock(stock_service: &State<StockService>, stock: Idempotent<Stock>) -> Result<Created<Json<Stock>>, (Status, Json<ErrorResponse>)> {
     match stock_service.create_stock(stock.into_inner()).await {
         Ok(created_stock) => {
             info!("Created stock successfully");
@@ -75,6 +76,10 @@ pub async fn update_stock(stock_service: &State<StockService>, stock: Json<Stock
             Ok(Json(updated_stock))
         },
         Ok(None) => Err((Status::NotFound, Json(ErrorResponse { message: "Stock not found".to_string() }))),
+        Err(e @ StockError::InvalidMovement(_)) => {
+            info!("Stock update failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse { message: e.to_string() })))
+        }
         Err(e) => {
             info!("Failed to update stock: {:?}", e);
             Err((Status::InternalServerError, Json(ErrorResponse { message: "Failed to update stock".to_string() })))
@@ -98,9 +103,56 @@ pub async fn delete_stock(stock_service: &State<StockService>, stock_id: i32) ->
             info!("No such stock found");
             Err((Status::NotFound, Json(ErrorResponse { message: "No such stock found".to_string() })))
         }
+        Err(e @ StockError::NotEmpty(_)) => {
+            info!("Stock deletion failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse { message: e.to_string() })))
+        }
         Err(e) => {
             info!("Error deleting stock with ID {}: {:?}", stock_id, e);
             Err((Status::InternalServerError, Json(ErrorResponse { message: "Error deleting stock".to_string() })))
         }
     }
 }
+
+/// Lists the stock ledger, oldest movement first.
+#[get("/stocks/movements?<query..>")]
+pub async fn get_stock_movements(stock_service: &State<StockService>, query: StockMovementQuery) -> Result<Json<Vec<StockMovement>>, (Status, Json<ErrorResponse>)> {
+    match stock_service.get_stock_movements(&query).await {
+        Ok(movements) => {
+            info!("Retrieved {} stock movements.", movements.len());
+            Ok(Json(movements))
+        }
+        Err(e @ StockError::InvalidQuery(_)) => {
+            info!("Stock movement query rejected: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse { message: e.to_string() })))
+        }
+        Err(e) => {
+            info!("Error retrieving stock movements: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse { message: "Failed to retrieve stock movements".to_string() })))
+        }
+    }
+}
+
+/// Records a receipt, adjustment or write-off made outside of any sale or return.
+#[post("/stocks/movements", data = "<movement>")]
+pub async fn create_stock_movement(stock_service: &State<StockService>, movement: Idempotent<NewStockMovement>) -> Result<Created<Json<StockMovement>>, (Status, Json<ErrorResponse>)> {
+    match stock_service.record_stock_movement(movement.into_inner()).await {
+        Ok(recorded) => {
+            info!("Recorded stock movement with ID: {}", recorded.movement_id);
+            let location = format!("/stocks/movements?store_id={}&product_id={}", recorded.store_id, recorded.product_id);
+            Ok(Created::new(location).body(Json(recorded)))
+        }
+        Err(e @ StockError::InvalidMovement(_)) => {
+            info!("Stock movement rejected: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse { message: e.to_string() })))
+        }
+        Err(e @ StockError::InsufficientStock { .. }) => {
+            info!("Stock movement rejected: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse { message: e.to_string() })))
+        }
+        Err(e) => {
+            info!("Failed to record stock movement: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse { message: "Failed to record stock movement".to_string() })))
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::config::Config;
+use crate::models::{ErrorResponse, LotExpiry, StockLot};
+use crate::services::{StockLotError, StockLotService};
+use tracing::info;
+
+/// Initializes a `StockLotService` instance using the provided `PgPool` and the configured expiry warning period.
+pub async fn stock_lot_service_init(pool: &PgPool, config: &Config) -> StockLotService {
+    StockLotService::new(pool.clone(), config.lot_expiry_warning_days).await
+}
+
+#[get("/stocks/lots?<store_id>&<product_id>")]
+pub async fn get_stock_lots(stock_lot_service: &State<StockLotService>, store_id: Option<i32>, product_id: Option<i32>) -> Result<Json<Vec<StockLot>>, (Status, Json<ErrorResponse>)> {
+    match stock_lot_service.get_lots(store_id, product_id).await {
+        Ok(lots) => {
+            info!("Retrieved {} stock lots.", lots.len());
+            Ok(Json(lots))
+        }
+        Err(e) => {
+            info!("Error retrieving stock lots: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve stock lots".to_string(),
+            })))
+        }
+    }
+}
+
+/// Lists the lots still held that expire within `days` days, or the configured warning period.
+#[get("/stocks/lots/expiring?<days>&<store_id>")]
+pub async fn get_expiring_lots(stock_lot_service: &State<StockLotService>, days: Option<i64>, store_id: Option<i32>) -> Result<Json<Vec<LotExpiry>>, (Status, Json<ErrorResponse>)> {
+    match stock_lot_service.get_expiring_lots(days, store_id).await {
+        Ok(lots) => {
+            info!("Found {} lots expiring soon.", lots.len());
+            Ok(Json(lots))
+        }
+        Err(e @ StockLotError::InvalidQuery(_)) => {
+            info!("Listing expiring lots failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error listing expiring lots: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to list expiring lots".to_string(),
+            })))
+        }
+    }
+}
+
+/// Lists the expired lots stores still hold.
+#[get("/stocks/lots/expired?<store_id>")]
+pub async fn get_expired_lots(stock_lot_service: &State<StockLotService>, store_id: Option<i32>) -> Result<Json<Vec<LotExpiry>>, (Status, Json<ErrorResponse>)> {
+    match stock_lot_service.get_expired_lots(store_id).await {
+        Ok(lots) => {
+            info!("Found {} expired lots still held.", lots.len());
+            Ok(Json(lots))
+        }
+        Err(e) => {
+            info!("Error listing expired lots: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to list expired lots".to_string(),
+            })))
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use rocket::http::Status;
+use rocket::response::status::Created;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::models::{ErrorResponse, GoodsInTransit, StockTransfer, TransferDispatch, TransferReceipt, TransferStatus};
+use crate::services::{StockTransferService, TransferError};
+use crate::controllers::Idempotent;
+use tracing::info;
+
+/// Initializes a `StockTransferService` instance using the provided `PgPool`.
+pub async fn stock_transfer_service_init(pool: &PgPool) -> StockTransferService {
+    StockTransferService::new(pool.clone()).await
+}
+
+#[get("/transfers/get-all?<status>&<store_id>")]
+pub async fn get_all_transfers(stock_transfer_service: &State<StockTransferService>, status: Option<TransferStatus>, store_id: Option<i32>) -> Result<Json<Vec<StockTransfer>>, (Status, Json<ErrorResponse>)> {
+    match stock_transfer_service.get_all_transfers(status, store_id).await {
+        Ok(transfers) => {
+            info!("Retrieved {} stock transfers.", transfers.len());
+            Ok(Json(transfers))
+        }
+        Err(e) => {
+            info!("Error retrieving stock transfers: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve stock transfers".to_string(),
+            })))
+        }
+    }
+}
+
+#[get("/transfers/by-id/<transfer_id>")]
+pub async fn get_transfer(stock_transfer_service: &State<StockTransferService>, transfer_id: i64) -> Result<Json<StockTransfer>, (Status, Json<ErrorResponse>)> {
+    match stock_transfer_service.get_transfer(transfer_id).await {
+        Ok(transfer) => {
+            info!("Successfully retrieved stock transfer with ID: {}", transfer_id);
+            Ok(Json(transfer))
+        }
+        Err(TransferError::NotFound) => {
+            info!("Stock transfer with ID {} not found.", transfer_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Stock transfer not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error retrieving stock transfer with ID {}: {:?}", transfer_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve stock transfer".to_string(),
+            })))
+        }
+    }
+}
+
+#[post("/transfers/create", data = "<transfer>")]
+pub async fn create_transfer(stock_transfer_service: &State<StockTransferService>, transfer: Idempotent<StockTransfer>) -> Result<Created<Json<StockTransfer>>, (Status, Json<ErrorResponse>)> {
+    match stock_transfer_service.create_transfer(transfer.into_inner()).await {
+        Ok(created) => {
+            info!("Requested stock transfer with ID: {}", created.transfer_id);
+            Ok(Created::new(format!("/transfers/by-id/{}", created.transfer_id)).body(Json(created)))
+        }
+        Err(e @ (TransferError::EmptyTransfer | TransferError::SameStore | TransferError::UnknownStore(_) | TransferError::InvalidLine(_))) => {
+            info!("Stock transfer request failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to request stock transfer: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to request stock transfer".to_string(),
+            })))
+        }
+    }
+}
+
+/// Sends a requested transfer, taking its goods out of the source store.
+#[post("/transfers/dispatch/<transfer_id>", data = "<dispatch>")]
+pub async fn dispatch_transfer(stock_transfer_service: &State<StockTransferService>, transfer_id: i64, dispatch: Json<TransferDispatch>) -> Result<Json<StockTransfer>, (Status, Json<ErrorResponse>)> {
+    match stock_transfer_service.dispatch_transfer(transfer_id, dispatch.into_inner()).await {
+        Ok(transfer) => {
+            info!("Dispatched stock transfer with ID: {}", transfer_id);
+            Ok(Json(transfer))
+        }
+        Err(TransferError::NotFound) => {
+            info!("Stock transfer with ID {} not found for dispatch.", transfer_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Stock transfer not found".to_string(),
+            })))
+        }
+        Err(e @ (TransferError::InvalidStatus { .. } | TransferError::InsufficientStock { .. })) => {
+            info!("Stock transfer dispatch failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to dispatch stock transfer {}: {:?}", transfer_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to dispatch stock transfer".to_string(),
+            })))
+        }
+    }
+}
+
+/// Takes in a dispatched transfer at the destination store, with any discrepancies.
+#[post("/transfers/receive/<transfer_id>", data = "<receipt>")]
+pub async fn receive_transfer(stock_transfer_service: &State<StockTransferService>, transfer_id: i64, receipt: Json<TransferReceipt>) -> Result<Json<StockTransfer>, (Status, Json<ErrorResponse>)> {
+    match stock_transfer_service.receive_transfer(transfer_id, receipt.into_inner()).await {
+        Ok(transfer) => {
+            info!("Received stock transfer with ID: {}", transfer_id);
+            Ok(Json(transfer))
+        }
+        Err(TransferError::NotFound) => {
+            info!("Stock transfer with ID {} not found for receipt.", transfer_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Stock transfer not found".to_string(),
+            })))
+        }
+        Err(e @ TransferError::InvalidReceipt(_)) => {
+            info!("Stock transfer receipt failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e @ TransferError::InvalidStatus { .. }) => {
+            info!("Stock transfer receipt failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to receive stock transfer {}: {:?}", transfer_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to receive stock transfer".to_string(),
+            })))
+        }
+    }
+}
+
+/// Calls off a transfer that has not been dispatched.
+#[post("/transfers/cancel/<transfer_id>")]
+pub async fn cancel_transfer(stock_transfer_service: &State<StockTransferService>, transfer_id: i64) -> Result<Json<StockTransfer>, (Status, Json<ErrorResponse>)> {
+    match stock_transfer_service.cancel_transfer(transfer_id).await {
+        Ok(transfer) => {
+            info!("Cancelled stock transfer with ID: {}", transfer_id);
+            Ok(Json(transfer))
+        }
+        Err(TransferError::NotFound) => {
+            info!("Stock transfer with ID {} not found for cancellation.", transfer_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Stock transfer not found".to_string(),
+            })))
+        }
+        Err(e @ TransferError::InvalidStatus { .. }) => {
+            info!("Stock transfer cancellation failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to cancel stock transfer {}: {:?}", transfer_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to cancel stock transfer".to_string(),
+            })))
+        }
+    }
+}
+
+/// Reports the goods dispatched between stores that have not been received yet.
+#[get("/transfers/in-transit?<store_id>")]
+pub async fn get_goods_in_transit(stock_transfer_service: &State<StockTransferService>, store_id: Option<i32>) -> Result<Json<Vec<GoodsInTransit>>, (Status, Json<ErrorResponse>)> {
+    match stock_transfer_service.get_goods_in_transit(store_id).await {
+        Ok(goods) => {
+            info!("Retrieved {} lines of goods in transit.", goods.len());
+            Ok(Json(goods))
+        }
+        Err(e) => {
+            info!("Error retrieving goods in transit: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve goods in transit".to_string(),
+            })))
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use rocket::http::Status;
+use rocket::response::status::Created;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::config::Config;
+use crate::models::{ErrorResponse, Stocktake, StocktakeClose, StocktakeCount, StocktakeCounts, StocktakeStatus, StocktakeVariance};
+use crate::services::{StocktakeError, StocktakeService};
+use crate::controllers::Idempotent;
+use tracing::info;
+
+/// Initializes a `StocktakeService` instance using the provided `PgPool` and the configured valuation method.
+pub async fn stocktake_service_init(pool: &PgPool, config: &Config) -> StocktakeService {
+    StocktakeService::new(pool.clone(), config.valuation_method).await
+}
+
+#[get("/stocktakes/get-all?<store_id>&<status>")]
+pub async fn get_all_stocktakes(stocktake_service: &State<StocktakeService>, store_id: Option<i32>, status: Option<StocktakeStatus>) -> Result<Json<Vec<Stocktake>>, (Status, Json<ErrorResponse>)> {
+    match stocktake_service.get_all_stocktakes(store_id, status).await {
+        Ok(stocktakes) => {
+            info!("Retrieved {} stocktakes.", stocktakes.len());
+            Ok(Json(stocktakes))
+        }
+        Err(e) => {
+            info!("Error retrieving stocktakes: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve stocktakes".to_string(),
+            })))
+        }
+    }
+}
+
+#[get("/stocktakes/by-id/<stocktake_id>")]
+pub async fn get_stocktake(stocktake_service: &State<StocktakeService>, stocktake_id: i64) -> Result<Json<Stocktake>, (Status, Json<ErrorResponse>)> {
+    match stocktake_service.get_stocktake(stocktake_id).await {
+        Ok(stocktake) => {
+            info!("Successfully retrieved stocktake with ID: {}", stocktake_id);
+            Ok(Json(stocktake))
+        }
+        Err(StocktakeError::NotFound) => {
+            info!("Stocktake with ID {} not found.", stocktake_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Stocktake not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error retrieving stocktake with ID {}: {:?}", stocktake_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve stocktake".to_string(),
+            })))
+        }
+    }
+}
+
+/// Opens a stocktake of a store, freezing the quantities it is expected to hold.
+#[post("/stocktakes/open", data = "<stocktake>")]
+pub async fn open_stocktake(stocktake_service: &State<StocktakeService>, stocktake: Idempotent<Stocktake>) -> Result<Created<Json<Stocktake>>, (Status, Json<ErrorResponse>)> {
+    match stocktake_service.open_stocktake(stocktake.into_inner()).await {
+        Ok(opened) => {
+            info!("Opened stocktake with ID: {}", opened.stocktake_id);
+            Ok(Created::new(format!("/stocktakes/by-id/{}", opened.stocktake_id)).body(Json(opened)))
+        }
+        Err(e @ StocktakeError::UnknownStore(_)) => {
+            info!("Opening stocktake failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e @ StocktakeError::AlreadyOpen { .. }) => {
+            info!("Opening stocktake failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to open stocktake: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to open stocktake".to_string(),
+            })))
+        }
+    }
+}
+
+/// Records a counter's counts in an open stocktake.
+#[post("/stocktakes/counts/<stocktake_id>", data = "<counts>")]
+pub async fn record_stocktake_counts(stocktake_service: &State<StocktakeService>, stocktake_id: i64, counts: Json<StocktakeCounts>) -> Result<Json<Stocktake>, (Status, Json<ErrorResponse>)> {
+    match stocktake_service.record_counts(stocktake_id, counts.into_inner()).await {
+        Ok(stocktake) => {
+            info!("Recorded counts in stocktake with ID: {}", stocktake_id);
+            Ok(Json(stocktake))
+        }
+        Err(StocktakeError::NotFound) => {
+            info!("Stocktake with ID {} not found for counting.", stocktake_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Stocktake not found".to_string(),
+            })))
+        }
+        Err(e @ StocktakeError::InvalidCount(_)) => {
+            info!("Recording stocktake counts failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e @ StocktakeError::InvalidStatus { .. }) => {
+            info!("Recording stocktake counts failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to record counts in stocktake {}: {:?}", stocktake_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to record stocktake counts".to_string(),
+            })))
+        }
+    }
+}
+
+#[get("/stocktakes/counts/<stocktake_id>")]
+pub async fn get_stocktake_counts(stocktake_service: &State<StocktakeService>, stocktake_id: i64) -> Result<Json<Vec<StocktakeCount>>, (Status, Json<ErrorResponse>)> {
+    match stocktake_service.get_counts(stocktake_id).await {
+        Ok(counts) => {
+            info!("Retrieved {} counts of stocktake with ID: {}", counts.len(), stocktake_id);
+            Ok(Json(counts))
+        }
+        Err(StocktakeError::NotFound) => {
+            info!("Stocktake with ID {} not found.", stocktake_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Stocktake not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error retrieving counts of stocktake {}: {:?}", stocktake_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve stocktake counts".to_string(),
+            })))
+        }
+    }
+}
+
+/// Closes an open stocktake, posting its variances to the store's stock.
+#[post("/stocktakes/close/<stocktake_id>", data = "<close>")]
+pub async fn close_stocktake(stocktake_service: &State<StocktakeService>, stocktake_id: i64, close: Json<StocktakeClose>) -> Result<Json<StocktakeVariance>, (Status, Json<ErrorResponse>)> {
+    match stocktake_service.close_stocktake(stocktake_id, close.into_inner()).await {
+        Ok(variance) => {
+            info!("Closed stocktake with ID {} with a net variance of {}.", stocktake_id, variance.net_variance_value);
+            Ok(Json(variance))
+        }
+        Err(StocktakeError::NotFound) => {
+            info!("Stocktake with ID {} not found for closing.", stocktake_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Stocktake not found".to_string(),
+            })))
+        }
+        Err(e @ StocktakeError::InvalidStatus { .. }) => {
+            info!("Closing stocktake failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to close stocktake {}: {:?}", stocktake_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to close stocktake".to_string(),
+            })))
+        }
+    }
+}
+
+/// Calls off an open stocktake without changing the store's stock.
+#[post("/stocktakes/cancel/<stocktake_id>")]
+pub async fn cancel_stocktake(stocktake_service: &State<StocktakeService>, stocktake_id: i64) -> Result<Json<Stocktake>, (Status, Json<ErrorResponse>)> {
+    match stocktake_service.cancel_stocktake(stocktake_id).await {
+        Ok(stocktake) => {
+            info!("Cancelled stocktake with ID: {}", stocktake_id);
+            Ok(Json(stocktake))
+        }
+        Err(StocktakeError::NotFound) => {
+            info!("Stocktake with ID {} not found for cancellation.", stocktake_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Stocktake not found".to_string(),
+            })))
+        }
+        Err(e @ StocktakeError::InvalidStatus { .. }) => {
+            info!("Stocktake cancellation failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to cancel stocktake {}: {:?}", stocktake_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to cancel stocktake".to_string(),
+            })))
+        }
+    }
+}
+
+/// Reports what a stocktake found against what was expected, in units and value.
+#[get("/stocktakes/variance/<stocktake_id>")]
+pub async fn get_stocktake_variance(stocktake_service: &State<StocktakeService>, stocktake_id: i64) -> Result<Json<StocktakeVariance>, (Status, Json<ErrorResponse>)> {
+    match stocktake_service.get_variance(stocktake_id).await {
+        Ok(variance) => {
+            info!("Reported the variance of stocktake with ID: {}", stocktake_id);
+            Ok(Json(variance))
+        }
+        Err(StocktakeError::NotFound) => {
+            info!("Stocktake with ID {} not found.", stocktake_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Stocktake not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error reporting the variance of stocktake {}: {:?}", stocktake_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to report stocktake variance".to_string(),
+            })))
+        }
+    }
+}
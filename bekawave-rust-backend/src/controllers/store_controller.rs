@@ -5,6 +5,7 @@ use rocket::State;
 use sqlx::{PgPool};
 use crate::models::{ErrorResponse, Store};
 use crate::services::{StoreError, StoreService};
+use crate::controllers::Idempotent;
 use tracing::info;
 
 
@@ -47,7 +48,7 @@ pub async fn get_store(store_service: &State<StoreService>, store_id: i32) -> Re
     }
 }
 #[post("/stores/create", data = "<store>")]
-pub async fn create_store(store_service: &State<StoreService>, store: Json<Store>) -> Result<Created<Json<Store>>, (Status, Json<ErrorResponse>)> {
+pub async fn create_store(store_service: &State<StoreService>, store: Idempotent<Store>) -> Result<Created<Json<Store>>, (Status, Json<ErrorResponse>)> {
     match store_service.create_store(store.into_inner()).await {
         Ok(created_store) => {
             info!("Created store successfully");
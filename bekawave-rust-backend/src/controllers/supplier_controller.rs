@@ -0,0 +1,130 @@
+use rocket::http::Status;
+use rocket::response::status::Created;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::models::{ErrorResponse, Supplier};
+use crate::services::{SupplierError, SupplierService};
+use crate::controllers::Idempotent;
+use tracing::info;
+
+/// Initializes a `SupplierService` instance using the provided `PgPool`.
+pub async fn supplier_service_init(pool: &PgPool) -> SupplierService {
+    SupplierService::new(pool.clone()).await
+}
+
+#[get("/suppliers/get-all")]
+pub async fn get_all_suppliers(supplier_service: &State<SupplierService>) -> Result<Json<Vec<Supplier>>, (Status, Json<ErrorResponse>)> {
+    match supplier_service.get_all_suppliers().await {
+        Ok(suppliers) => {
+            info!("Retrieved {} suppliers.", suppliers.len());
+            Ok(Json(suppliers))
+        }
+        Err(e) => {
+            info!("Error retrieving suppliers: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve suppliers".to_string(),
+            })))
+        }
+    }
+}
+
+#[get("/suppliers/by-id/<supplier_id>")]
+pub async fn get_supplier(supplier_service: &State<SupplierService>, supplier_id: i32) -> Result<Json<Supplier>, (Status, Json<ErrorResponse>)> {
+    match supplier_service.get_supplier(supplier_id).await {
+        Ok(supplier) => {
+            info!("Successfully retrieved supplier with ID: {}", supplier_id);
+            Ok(Json(supplier))
+        }
+        Err(SupplierError::NotFound) => {
+            info!("Supplier with ID {} not found.", supplier_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Supplier not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error retrieving supplier with ID {}: {:?}", supplier_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve supplier".to_string(),
+            })))
+        }
+    }
+}
+
+#[post("/suppliers/create", data = "<supplier>")]
+pub async fn create_supplier(supplier_service: &State<SupplierService>, supplier: Idempotent<Supplier>) -> Result<Created<Json<Supplier>>, (Status, Json<ErrorResponse>)> {
+    match supplier_service.create_supplier(supplier.into_inner()).await {
+        Ok(created) => {
+            info!("Created supplier with ID: {}", created.supplier_id);
+            Ok(Created::new(format!("/suppliers/by-id/{}", created.supplier_id)).body(Json(created)))
+        }
+        Err(e @ SupplierError::DuplicateSupplier) => {
+            info!("Creating supplier failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to create supplier: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to create supplier".to_string(),
+            })))
+        }
+    }
+}
+
+#[put("/suppliers/update", data = "<supplier>")]
+pub async fn update_supplier(supplier_service: &State<SupplierService>, supplier: Json<Supplier>) -> Result<Json<Supplier>, (Status, Json<ErrorResponse>)> {
+    match supplier_service.update_supplier(supplier.into_inner()).await {
+        Ok(updated) => {
+            info!("Updated supplier with ID: {}", updated.supplier_id);
+            Ok(Json(updated))
+        }
+        Err(SupplierError::NotFound) => {
+            info!("Supplier not found for update.");
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Supplier not found".to_string(),
+            })))
+        }
+        Err(e @ SupplierError::DuplicateSupplier) => {
+            info!("Updating supplier failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to update supplier: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to update supplier".to_string(),
+            })))
+        }
+    }
+}
+
+#[delete("/suppliers/delete/<supplier_id>")]
+pub async fn delete_supplier(supplier_service: &State<SupplierService>, supplier_id: i32) -> Result<Status, (Status, Json<ErrorResponse>)> {
+    match supplier_service.delete_supplier(supplier_id).await {
+        Ok(()) => {
+            info!("Deleted supplier with ID: {}", supplier_id);
+            Ok(Status::NoContent)
+        }
+        Err(SupplierError::NotFound) => {
+            info!("Supplier with ID {} not found for deletion.", supplier_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Supplier not found".to_string(),
+            })))
+        }
+        Err(e @ SupplierError::InUse) => {
+            info!("Deleting supplier failed: {}", e);
+            Err((Status::Conflict, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to delete supplier {}: {:?}", supplier_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to delete supplier".to_string(),
+            })))
+        }
+    }
+}
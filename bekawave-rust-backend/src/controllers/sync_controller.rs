@@ -0,0 +1,39 @@
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::models::{ErrorResponse, SyncRequest, SyncResponse};
+use crate::services::{SalesService, SyncError, SyncService};
+use tracing::info;
+
+/// Initializes a `SyncService` instance using the provided `PgPool`.
+pub async fn sync_service_init(pool: &PgPool) -> SyncService {
+    SyncService::new(pool.clone()).await
+}
+
+/// Applies the operations a rep's device recorded offline and returns what changed on the server since its last sync.
+///
+/// Operations that conflict or are rejected are reported in the results; the batch itself only fails if it is invalid
+/// or the database fails, in which case nothing is applied.
+#[post("/sync", data = "<request>")]
+pub async fn sync(sync_service: &State<SyncService>, sales_service: &State<SalesService>, request: Json<SyncRequest>) -> Result<Json<SyncResponse>, (Status, Json<ErrorResponse>)> {
+    let device_id = request.device_id.clone();
+    match sync_service.sync(sales_service, request.into_inner()).await {
+        Ok(response) => {
+            info!("Synced {} operations from device {}.", response.results.len(), device_id);
+            Ok(Json(response))
+        }
+        Err(e @ SyncError::InvalidRequest(_)) => {
+            info!("Sync from device {} failed: {}", device_id, e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error syncing device {}: {:?}", device_id, e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to sync".to_string(),
+            })))
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::models::{ErrorResponse, TaxSummary};
+use crate::services::{TaxError, TaxService};
+use tracing::info;
+
+/// Initializes a `TaxService` instance using the provided `PgPool`.
+pub async fn tax_service_init(pool: &PgPool) -> TaxService {
+    TaxService::new(pool.clone()).await
+}
+
+/// Summarises the VAT on the sales and returns made between `from` and `to`.
+#[get("/tax/summary?<from>&<to>")]
+pub async fn get_tax_summary(tax_service: &State<TaxService>, from: Option<String>, to: Option<String>) -> Result<Json<Vec<TaxSummary>>, (Status, Json<ErrorResponse>)> {
+    match tax_service.get_tax_summary(from.as_deref(), to.as_deref()).await {
+        Ok(summary) => {
+            info!("Summarised VAT over {} tax rates.", summary.len());
+            Ok(Json(summary))
+        }
+        Err(e @ TaxError::InvalidQuery(_)) => {
+            info!("Tax summary failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to summarise VAT: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to summarise VAT".to_string(),
+            })))
+        }
+    }
+}
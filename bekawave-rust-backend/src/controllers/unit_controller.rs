@@ -0,0 +1,86 @@
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::models::{ErrorResponse, ProductUnit, ProductUnits};
+use crate::services::{UnitError, UnitService};
+use tracing::info;
+
+/// Initializes a `UnitService` instance using the provided `PgPool`.
+pub async fn unit_service_init(pool: &PgPool) -> UnitService {
+    UnitService::new(pool.clone()).await
+}
+
+/// Lists the base unit of a product and the other units it can be counted in.
+#[get("/products/units/<product_id>")]
+pub async fn get_product_units(unit_service: &State<UnitService>, product_id: i32) -> Result<Json<ProductUnits>, (Status, Json<ErrorResponse>)> {
+    match unit_service.get_product_units(product_id).await {
+        Ok(units) => {
+            info!("Retrieved {} units of product {}.", units.units.len(), product_id);
+            Ok(Json(units))
+        }
+        Err(UnitError::ProductNotFound) => {
+            info!("Product with ID {} not found.", product_id);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Product not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error retrieving product units: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to retrieve product units".to_string(),
+            })))
+        }
+    }
+}
+
+/// Sets how many base units a unit of a product holds, adding the unit if the product has none of that name.
+#[put("/products/units", data = "<unit>")]
+pub async fn set_product_unit(unit_service: &State<UnitService>, unit: Json<ProductUnit>) -> Result<Json<ProductUnit>, (Status, Json<ErrorResponse>)> {
+    match unit_service.set_product_unit(unit.into_inner()).await {
+        Ok(stored) => {
+            info!("Set unit '{}' of product {} to {} base units", stored.unit, stored.product_id, stored.factor);
+            Ok(Json(stored))
+        }
+        Err(e @ UnitError::InvalidUnit(_)) => {
+            info!("Setting product unit failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(UnitError::ProductNotFound) => {
+            info!("Setting product unit failed: product not found.");
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Product not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Failed to set product unit: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to set product unit".to_string(),
+            })))
+        }
+    }
+}
+
+#[delete("/products/units/<product_id>/<unit>")]
+pub async fn delete_product_unit(unit_service: &State<UnitService>, product_id: i32, unit: &str) -> Result<Status, (Status, Json<ErrorResponse>)> {
+    match unit_service.delete_product_unit(product_id, unit).await {
+        Ok(()) => {
+            info!("Deleted unit '{}' of product {}", unit, product_id);
+            Ok(Status::NoContent)
+        }
+        Err(UnitError::NotFound) => {
+            info!("Product {} has no unit '{}'.", product_id, unit);
+            Err((Status::NotFound, Json(ErrorResponse {
+                message: "Product unit not found".to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error deleting product unit: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to delete product unit".to_string(),
+            })))
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::State;
+use sqlx::PgPool;
+use crate::config::Config;
+use crate::models::{CostOfGoodsSold, ErrorResponse, InventoryValuation, StockPositionComparison, StockPositions};
+use crate::services::{ValuationError, ValuationService};
+use tracing::info;
+
+/// Initializes a `ValuationService` instance using the provided `PgPool` and the configured valuation method.
+pub async fn valuation_service_init(pool: &PgPool, config: &Config) -> ValuationService {
+    ValuationService::new(pool.clone(), config.valuation_method).await
+}
+
+/// Values the stock on hand per store, per product and in total, as of `as_of` or now.
+#[get("/stocks/valuation?<as_of>&<store_id>")]
+pub async fn get_inventory_valuation(valuation_service: &State<ValuationService>, as_of: Option<String>, store_id: Option<i32>) -> Result<Json<InventoryValuation>, (Status, Json<ErrorResponse>)> {
+    match valuation_service.get_inventory_valuation(as_of.as_deref(), store_id).await {
+        Ok(valuation) => {
+            info!("Valued {} lines of stock at {} as of {}.", valuation.lines.len(), valuation.total_value, valuation.as_of);
+            Ok(Json(valuation))
+        }
+        Err(e @ ValuationError::InvalidQuery(_)) => {
+            info!("Stock valuation failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error valuing stock: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to value stock".to_string(),
+            })))
+        }
+    }
+}
+
+/// Works out the cost of the goods sold between `from` and `to`.
+#[get("/stocks/cost-of-goods-sold?<from>&<to>&<store_id>")]
+pub async fn get_cost_of_goods_sold(valuation_service: &State<ValuationService>, from: Option<String>, to: Option<String>, store_id: Option<i32>) -> Result<Json<CostOfGoodsSold>, (Status, Json<ErrorResponse>)> {
+    match valuation_service.get_cost_of_goods_sold(from.as_deref(), to.as_deref(), store_id).await {
+        Ok(cogs) => {
+            info!("Costed {} lines of goods sold at {}.", cogs.lines.len(), cogs.total_cost);
+            Ok(Json(cogs))
+        }
+        Err(e @ ValuationError::InvalidQuery(_)) => {
+            info!("Costing goods sold failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error costing goods sold: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to cost goods sold".to_string(),
+            })))
+        }
+    }
+}
+
+/// Rebuilds what each store held of each product as of `as_of` or now, also in `unit` when given.
+#[get("/stocks/positions?<as_of>&<store_id>&<product_id>&<unit>")]
+pub async fn get_stock_positions(valuation_service: &State<ValuationService>, as_of: Option<String>, store_id: Option<i32>, product_id: Option<i32>, unit: Option<String>) -> Result<Json<StockPositions>, (Status, Json<ErrorResponse>)> {
+    match valuation_service.get_stock_positions(as_of.as_deref(), store_id, product_id, unit.as_deref()).await {
+        Ok(positions) => {
+            info!("Rebuilt {} stock positions as of {}.", positions.lines.len(), positions.as_of);
+            Ok(Json(positions))
+        }
+        Err(e @ ValuationError::InvalidQuery(_)) => {
+            info!("Rebuilding stock positions failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error rebuilding stock positions: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to rebuild stock positions".to_string(),
+            })))
+        }
+    }
+}
+
+/// Compares the stock held at `from` with the stock held at `to` or now, also in `unit` when given.
+#[get("/stocks/positions/compare?<from>&<to>&<store_id>&<product_id>&<unit>")]
+pub async fn compare_stock_positions(valuation_service: &State<ValuationService>, from: Option<String>, to: Option<String>, store_id: Option<i32>, product_id: Option<i32>, unit: Option<String>) -> Result<Json<StockPositionComparison>, (Status, Json<ErrorResponse>)> {
+    match valuation_service.compare_stock_positions(from.as_deref(), to.as_deref(), store_id, product_id, unit.as_deref()).await {
+        Ok(comparison) => {
+            info!("Compared {} stock positions from {} to {}.", comparison.lines.len(), comparison.from, comparison.to);
+            Ok(Json(comparison))
+        }
+        Err(e @ ValuationError::InvalidQuery(_)) => {
+            info!("Comparing stock positions failed: {}", e);
+            Err((Status::BadRequest, Json(ErrorResponse {
+                message: e.to_string(),
+            })))
+        }
+        Err(e) => {
+            info!("Error comparing stock positions: {:?}", e);
+            Err((Status::InternalServerError, Json(ErrorResponse {
+                message: "Failed to compare stock positions".to_string(),
+            })))
+        }
+    }
+}
@@ -1,9 +1,14 @@
 #[macro_use]
 extern crate rocket;
-use sqlx::{Executor, PgPool};
+use chrono::Local;
+use sqlx::PgPool;
 use crate::controllers::*;
 use rocket::http::Method;
 use rocket_cors::{AllowedHeaders, AllowedOrigins, Cors, CorsOptions};
+use crate::config::Config;
+use crate::migrations::{is_applied, migrate, track_migrations};
+mod config;
+mod migrations;
 mod models;
 mod services;
 mod controllers;
@@ -21,6 +26,7 @@ fn make_cors() -> Cors {
             "Accept",
             "Content-Type",
             "Access-Control-Allow-Origin",
+            "Idempotency-Key",
         ]),
         allow_credentials: false,
         ..Default::default()
@@ -29,37 +35,163 @@ fn make_cors() -> Cors {
 }
 #[shuttle_runtime::main]
 async fn rocket(#[shuttle_shared_db::Postgres] pool: PgPool) -> shuttle_rocket::ShuttleRocket {
+    let config = Config::from_env();
     let customer_service = customer_service_init(&pool).await;
     let debt_service = debt_service_init(&pool).await;
     let product_service = product_service_init(&pool).await;
-    let sales_service = sales_service_init(&pool).await;
+    let sales_service = sales_service_init(&pool, &config).await;
+    let sales_return_service = sales_return_service_init(&pool).await;
+    let invoice_service = invoice_service_init(&pool, &config).await;
+    let price_list_service = price_list_service_init(&pool).await;
+    let promotion_service = promotion_service_init(&pool).await;
+    let tax_service = tax_service_init(&pool).await;
+    let quotation_service = quotation_service_init(&pool).await;
+    let idempotency_service = idempotency_service_init(&pool, &config).await;
+    let sync_service = sync_service_init(&pool).await;
     let sales_pair_service = sales_pair_service_init(&pool).await;
     let stock_service = stock_service_init(&pool).await;
+    let stock_transfer_service = stock_transfer_service_init(&pool).await;
+    let reorder_service = reorder_service_init(&pool).await;
+    let valuation_service = valuation_service_init(&pool, &config).await;
+    let stocktake_service = stocktake_service_init(&pool, &config).await;
+    let supplier_service = supplier_service_init(&pool).await;
+    let purchase_order_service = purchase_order_service_init(&pool).await;
+    let stock_lot_service = stock_lot_service_init(&pool, &config).await;
+    let unit_service = unit_service_init(&pool).await;
     let store_service = store_service_init(&pool).await;
     let sales_rep_service = sales_rep_init(&pool).await;
 
-    pool.execute(include_str!("../migrations/0002_02_validate_db.sql"))
+    track_migrations(&pool)
+        .await
+        .expect("Unable to track migrations :( ");
+    migrate(&pool, "0002_02_validate_db", include_str!("../migrations/0002_02_validate_db.sql"))
         .await
         .expect("Unable to connect and execute the query :( ");
+    migrate(&pool, "0003_sales_orders", include_str!("../migrations/0003_sales_orders.sql"))
+        .await
+        .expect("Unable to migrate sales into orders :( ");
+    migrate(&pool, "0004_sales_pricing", include_str!("../migrations/0004_sales_pricing.sql"))
+        .await
+        .expect("Unable to migrate sales pricing :( ");
+    migrate(&pool, "0005_sales_returns", include_str!("../migrations/0005_sales_returns.sql"))
+        .await
+        .expect("Unable to migrate sales returns :( ");
+    migrate(&pool, "0006_void_sales", include_str!("../migrations/0006_void_sales.sql"))
+        .await
+        .expect("Unable to migrate sales voiding :( ");
+    migrate(&pool, "0007_credit_sales", include_str!("../migrations/0007_credit_sales.sql"))
+        .await
+        .expect("Unable to migrate credit sales :( ");
+    migrate(&pool, "0008_invoice_numbers", include_str!("../migrations/0008_invoice_numbers.sql"))
+        .await
+        .expect("Unable to migrate invoice numbers :( ");
+    if !is_applied(&pool, "0009_sales_store_required").await.expect("Unable to read applied migrations :( ") {
+        sales_service.assign_default_store(config.default_store_id)
+            .await
+            .expect("Unable to attribute sales to the default store, check DEFAULT_STORE_ID :( ");
+    }
+    migrate(&pool, "0009_sales_store_required", include_str!("../migrations/0009_sales_store_required.sql"))
+        .await
+        .expect("Unable to require a store on sales :( ");
+    migrate(&pool, "0010_price_tiers", include_str!("../migrations/0010_price_tiers.sql"))
+        .await
+        .expect("Unable to migrate price tiers :( ");
+    migrate(&pool, "0011_promotions", include_str!("../migrations/0011_promotions.sql"))
+        .await
+        .expect("Unable to migrate promotions :( ");
+    migrate(&pool, "0012_sales_tax", include_str!("../migrations/0012_sales_tax.sql"))
+        .await
+        .expect("Unable to migrate sales tax :( ");
+    migrate(&pool, "0013_quotations", include_str!("../migrations/0013_quotations.sql"))
+        .await
+        .expect("Unable to migrate quotations :( ");
+    migrate(&pool, "0014_idempotency_keys", include_str!("../migrations/0014_idempotency_keys.sql"))
+        .await
+        .expect("Unable to migrate idempotency keys :( ");
+    migrate(&pool, "0015_offline_sync", include_str!("../migrations/0015_offline_sync.sql"))
+        .await
+        .expect("Unable to migrate offline sync :( ");
+    migrate(&pool, "0016_stock_movements", include_str!("../migrations/0016_stock_movements.sql"))
+        .await
+        .expect("Unable to migrate stock movements :( ");
+    migrate(&pool, "0017_stock_transfers", include_str!("../migrations/0017_stock_transfers.sql"))
+        .await
+        .expect("Unable to migrate stock transfers :( ");
+    migrate(&pool, "0018_reorder_levels", include_str!("../migrations/0018_reorder_levels.sql"))
+        .await
+        .expect("Unable to migrate reorder levels :( ");
+    migrate(&pool, "0019_stock_valuation", include_str!("../migrations/0019_stock_valuation.sql"))
+        .await
+        .expect("Unable to migrate stock valuation :( ");
+    migrate(&pool, "0020_stocktakes", include_str!("../migrations/0020_stocktakes.sql"))
+        .await
+        .expect("Unable to migrate stocktakes :( ");
+    migrate(&pool, "0021_purchase_orders", include_str!("../migrations/0021_purchase_orders.sql"))
+        .await
+        .expect("Unable to migrate purchase orders :( ");
+    migrate(&pool, "0022_stock_lots", include_str!("../migrations/0022_stock_lots.sql"))
+        .await
+        .expect("Unable to migrate stock lots :( ");
+    migrate(&pool, "0023_units_of_measure", include_str!("../migrations/0023_units_of_measure.sql"))
+        .await
+        .expect("Unable to migrate units of measure :( ");
+    idempotency_service.release_abandoned(Local::now().naive_local())
+        .await
+        .expect("Unable to release abandoned idempotency keys :( ");
 
     let rocket = rocket::build()
         .attach(make_cors())
+        .attach(Idempotency)
         .manage(pool)
         .manage(store_service)
         .manage(sales_rep_service)
         .manage(stock_service)
+        .manage(stock_transfer_service)
+        .manage(reorder_service)
+        .manage(valuation_service)
+        .manage(stocktake_service)
+        .manage(supplier_service)
+        .manage(purchase_order_service)
+        .manage(stock_lot_service)
+        .manage(unit_service)
         .manage(sales_pair_service)
         .manage(sales_service)
+        .manage(sales_return_service)
+        .manage(invoice_service)
+        .manage(price_list_service)
+        .manage(promotion_service)
+        .manage(tax_service)
+        .manage(quotation_service)
+        .manage(idempotency_service)
+        .manage(sync_service)
         .manage(product_service)
         .manage(debt_service)
         .manage(customer_service)
         .mount("/", routes![
-            delete_customer, delete_store, delete_debt, delete_sales_rep, delete_sales, delete_product, delete_sales_pair, delete_stock,
+            delete_customer, delete_store, delete_debt, delete_sales_rep, delete_product, delete_sales_pair, delete_stock,
             get_all_customers, get_all_stores, get_all_debts, get_all_sales_reps, get_all_sales, get_all_products, get_all_sales_pairs, get_all_stocks,
             get_customer, get_store, get_debt, get_sales_rep, get_sales, get_product, get_sales_pair, get_stock,
             update_customer, update_store, update_debt, update_sales_rep, update_sales, update_product, update_sales_pair, update_stock,
             create_customer, create_store, create_debt, create_sales_rep, create_sales, create_product, create_sales_pair, create_stock,
-            download_route
+            download_route,
+            void_sales, get_voided_sales, get_customer_debts,
+            create_sales_return, get_sales_return, get_all_sales_returns, get_sales_returns_for_sale,
+            get_sales_invoice, get_sales_by_invoice_number,
+            get_tier_prices, set_tier_price, delete_tier_price, get_customer_prices, set_customer_price, delete_customer_price, get_effective_price,
+            preview_sales, get_all_promotions, get_promotion, create_promotion, update_promotion, delete_promotion, get_promotion_costs,
+            get_tax_summary,
+            get_all_quotations, get_quotation, create_quotation, update_quotation, set_quotation_status, delete_quotation, convert_quotation,
+            download_quotations_route,
+            sync,
+            get_stock_movements, create_stock_movement,
+            get_all_transfers, get_transfer, create_transfer, dispatch_transfer, receive_transfer, cancel_transfer, get_goods_in_transit,
+            get_reorder_levels, set_reorder_level, delete_reorder_level, get_low_stock, get_stock_alerts, acknowledge_stock_alert, stream_stock_alerts,
+            get_inventory_valuation, get_cost_of_goods_sold, get_stock_positions, compare_stock_positions,
+            get_all_stocktakes, get_stocktake, open_stocktake, record_stocktake_counts, get_stocktake_counts, close_stocktake, cancel_stocktake, get_stocktake_variance,
+            get_all_suppliers, get_supplier, create_supplier, update_supplier, delete_supplier,
+            get_all_purchase_orders, get_purchase_order, create_purchase_order, receive_purchase_order, get_goods_received_notes, get_purchase_order_fulfilment, close_purchase_order, cancel_purchase_order,
+            get_stock_lots, get_expiring_lots, get_expired_lots,
+            get_product_units, set_product_unit, delete_product_unit
         ]);
 
     Ok(rocket.into())
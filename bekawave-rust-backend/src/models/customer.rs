@@ -1,6 +1,26 @@
+use rocket::FromFormField;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
+/// The kind of customer, which decides the price list a customer buys from.
+///
+/// Stored in the database as the `customer_tier` enum type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "customer_tier", rename_all = "snake_case")]
+pub enum CustomerTier {
+    /// Walk-in customers and shop owners buying for themselves.
+    #[default]
+    #[field(value = "retail")]
+    Retail,
+    /// Customers buying in bulk to resell.
+    #[field(value = "wholesale")]
+    Wholesale,
+    /// Distributors supplying other resellers.
+    #[field(value = "distributor")]
+    Distributor,
+}
+
 /// Represents a customer record in the database.
 ///
 /// # Fields
@@ -8,13 +28,15 @@ use sqlx::FromRow;
 /// - `name`: The name of the customer.
 /// - `phone_no`: The phone number of the customer.
 /// - `location`: The location of the customer.
+/// - `tier`: The price tier of the customer. Defaults to `CustomerTier::Retail`.
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 pub struct Customer {
     pub customer_id: i64,
     pub name: String,
     pub phone_no: String,
-    pub location: String
-
+    pub location: String,
+    #[serde(default)]
+    pub tier: CustomerTier,
 }
 /// Constructs a new `Customer` instance.
 ///
@@ -47,8 +69,9 @@ pub struct Customer {
 /// # Notes
 /// - The `customer_id` field is initialized to `0` and should be updated with a value assigned by the database after insertion.
 impl Customer {
+    #[allow(dead_code)]
     pub fn new(customer_id: i64, name: String, phone_no: String, location: String) -> Self {
-        Self { customer_id, name, phone_no, location }
+        Self { customer_id, name, phone_no, location, tier: CustomerTier::default() }
     }
 }
 
@@ -11,14 +11,53 @@ use sqlx::FromRow;
 /// - `customer_date`: The date when the debt was incurred.
 /// - `paid_date`: The date when the debt was paid. This field is `None` if the debt is not paid yet.
 /// - `is_paid`: A boolean indicating whether the debt has been paid or not.
+/// - `sales_id`: The ID of the sales order the debt came from, if it was created by a credit or partial sale.
+/// - `cancelled_at`: When the debt was cancelled because its sale was voided or changed, or because goods from the sale
+///   were returned against it. A cancelled debt is no longer owed. `None` if it was not cancelled.
+/// - `cancel_reason`: Why the debt was cancelled.
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 pub struct Debt {
     pub debt_id: i64,
     pub customer_id: i32,
-    pub amount: i32,
+    pub amount: i64,
     pub customer_date: NaiveDateTime,
     pub paid_date: Option<NaiveDateTime>,
     pub is_paid: bool,
+    #[serde(default)]
+    pub sales_id: Option<i64>,
+    #[serde(default)]
+    pub cancelled_at: Option<NaiveDateTime>,
+    #[serde(default)]
+    pub cancel_reason: Option<String>,
+}
+
+/// A debt together with the goods it was incurred for.
+///
+/// # Fields
+/// - `debt`: The debt record. Its fields are serialized inline.
+/// - `goods`: The lines of the sales order behind the debt. Empty for debts that were not created by a sale.
+#[derive(Debug, Serialize)]
+pub struct DebtWithGoods {
+    #[serde(flatten)]
+    pub debt: Debt,
+    pub goods: Vec<DebtGoods>,
+}
+
+/// A product sold on the sales order behind a debt.
+///
+/// # Fields
+/// - `sales_id`: The ID of the sales order.
+/// - `product_id`: The ID of the product.
+/// - `product_name`: The name of the product.
+/// - `product_quantity`: The quantity sold.
+/// - `total_price`: The price of the line in cents.
+#[derive(Debug, Serialize, FromRow)]
+pub struct DebtGoods {
+    pub sales_id: i64,
+    pub product_id: i32,
+    pub product_name: String,
+    pub product_quantity: i32,
+    pub total_price: i64,
 }
 
 /// Constructs a new `Debt` instance.
@@ -59,7 +98,8 @@ pub struct Debt {
 ///
 /// # Notes
 /// - The `debt_id` field is initialized to `0` and should be updated with a value assigned by the database after insertion.
-pub fn build_debt(customer_id: i32, amount: i32, customer_date: NaiveDateTime, paid_date: Option<NaiveDateTime>, is_paid: bool) -> Debt {
+#[allow(dead_code)]
+pub fn build_debt(customer_id: i32, amount: i64, customer_date: NaiveDateTime, paid_date: Option<NaiveDateTime>, is_paid: bool) -> Debt {
     Debt {
         debt_id: 0,
         customer_id,
@@ -67,5 +107,8 @@ pub fn build_debt(customer_id: i32, amount: i32, customer_date: NaiveDateTime, p
         customer_date,
         paid_date,
         is_paid,
+        sales_id: None,
+        cancelled_at: None,
+        cancel_reason: None,
     }
 }
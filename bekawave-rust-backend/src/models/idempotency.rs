@@ -0,0 +1,38 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+/// The name of the request header clients send an idempotency key in.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// The longest idempotency key accepted.
+pub const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
+
+/// A request made with an `Idempotency-Key` header and the response it was first given.
+///
+/// # Fields
+/// - `idempotency_key`: The key the client sent. Clients should generate a new random key, e.g. a UUID, per operation
+///   and send the same key again when retrying it.
+/// - `request_path`: The path the request was made to, e.g. `/sales/create`.
+/// - `request_body`: The body of the request, as sent.
+/// - `response_status`: The HTTP status of the first response, or `None` while the request is being handled.
+/// - `response_content_type`: The content type of the first response.
+/// - `response_location`: The `Location` header of the first response, set for created records.
+/// - `response_body`: The body of the first response.
+/// - `created_at`: When the key was first used. Keys are forgotten once they are older than the configured window.
+/// - `claimed_at`: When the request handling the key claimed it. Claims made before the server last started and never
+///   answered are given up when it starts.
+///
+/// # Notes
+/// - Responses with a server error status are not kept, so a request that failed that way can be retried with the same key.
+#[derive(Debug, Clone, FromRow)]
+pub struct IdempotencyRecord {
+    pub idempotency_key: String,
+    pub request_path: String,
+    pub request_body: String,
+    pub response_status: Option<i32>,
+    pub response_content_type: Option<String>,
+    pub response_location: Option<String>,
+    pub response_body: Option<Vec<u8>>,
+    pub created_at: NaiveDateTime,
+    pub claimed_at: NaiveDateTime,
+}
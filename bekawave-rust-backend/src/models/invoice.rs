@@ -0,0 +1,78 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use crate::models::PaymentMode;
+
+/// The business details printed at the top of every invoice.
+///
+/// # Fields
+/// - `name`: The trading name of the business.
+/// - `address`: The postal or street address of the business. Left off the invoice when empty.
+/// - `phone`: The phone number of the business. Left off the invoice when empty.
+#[derive(Debug, Clone, Serialize)]
+pub struct BusinessDetails {
+    pub name: String,
+    pub address: String,
+    pub phone: String,
+}
+
+/// Everything printed on the invoice of a sales order.
+///
+/// # Fields
+/// - `business`: The business header.
+/// - `sales_id`: The ID of the sales order.
+/// - `invoice_number`: The store's invoice number of the sale. Sales recorded before invoice numbers existed print their `sales_id` instead.
+/// - `sales_time`: When the sale was made.
+/// - `store_name`: The name of the store the sale was made from, if any.
+/// - `customer_name`: The name of the customer.
+/// - `customer_phone`: The phone number of the customer.
+/// - `sold_by`: The sales representative, or both representatives of the sales pair, who made the sale.
+/// - `lines`: The products sold.
+/// - `net_total`: The total of the order in cents before VAT.
+/// - `tax_total`: The VAT on the order in cents.
+/// - `total_price`: The total of the order in cents, VAT included.
+/// - `payment_mode`: How the order was paid for.
+/// - `amount_paid`: The amount in cents paid at the time of sale.
+/// - `balance_due`: The amount in cents still owed on this sale.
+/// - `customer_balance`: The amount in cents the customer owes over all their unpaid debts, this sale included.
+/// - `voided`: Whether the sale has been voided. Voided invoices are marked as such.
+#[derive(Debug, Serialize)]
+pub struct Invoice {
+    pub business: BusinessDetails,
+    pub sales_id: i64,
+    pub invoice_number: Option<String>,
+    pub sales_time: NaiveDateTime,
+    pub store_name: Option<String>,
+    pub customer_name: String,
+    pub customer_phone: String,
+    pub sold_by: Option<String>,
+    pub lines: Vec<InvoiceLine>,
+    pub net_total: i64,
+    pub tax_total: i64,
+    pub total_price: i64,
+    pub payment_mode: PaymentMode,
+    pub amount_paid: i64,
+    pub balance_due: i64,
+    pub customer_balance: i64,
+    pub voided: bool,
+}
+
+/// A product sold on an invoice.
+///
+/// # Fields
+/// - `product_name`: The name of the product.
+/// - `quantity`: The quantity sold.
+/// - `unit_price`: The price of one unit in cents at the time of sale.
+/// - `discount`: The discount in cents taken off the line, promotions included.
+/// - `net_price`: The price of the line in cents before VAT.
+/// - `tax_amount`: The VAT on the line in cents.
+/// - `total_price`: The price of the line in cents, VAT included.
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct InvoiceLine {
+    pub product_name: String,
+    pub quantity: i32,
+    pub unit_price: i64,
+    pub discount: i64,
+    pub net_price: i64,
+    pub tax_amount: i64,
+    pub total_price: i64,
+}
@@ -1,4 +1,14 @@
 pub mod stock;
+mod stock_movement;
+mod stock_transfer;
+mod reorder;
+mod valuation;
+mod stocktake;
+mod supplier;
+mod purchase_order;
+mod stock_lot;
+mod unit;
+mod stock_position;
 pub mod store;
 mod debt;
 mod sales_rep;
@@ -6,10 +16,29 @@ mod product;
 mod sales_pair;
 mod customer;
 mod sales;
+mod sales_line;
+mod sales_return;
+mod sales_query;
+mod invoice;
+mod price_list;
+mod promotion;
+mod tax;
+mod quotation;
+mod idempotency;
+mod sync;
 mod error_response;
-mod response;
 
 pub use stock::*;
+pub use stock_movement::*;
+pub use stock_transfer::*;
+pub use reorder::*;
+pub use valuation::*;
+pub use stocktake::*;
+pub use supplier::*;
+pub use purchase_order::*;
+pub use stock_lot::*;
+pub use unit::*;
+pub use stock_position::*;
 pub use store::*;
 pub use error_response::ErrorResponse;
 pub use product::*;
@@ -18,4 +47,13 @@ pub use debt::*;
 pub use sales_rep::*;
 pub use customer::*;
 pub use sales::*;
-pub use response::*;
\ No newline at end of file
+pub use sales_line::*;
+pub use sales_return::*;
+pub use sales_query::*;
+pub use invoice::*;
+pub use price_list::*;
+pub use promotion::*;
+pub use tax::*;
+pub use quotation::*;
+pub use idempotency::*;
+pub use sync::*;
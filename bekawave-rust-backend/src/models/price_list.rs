@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use crate::models::CustomerTier;
+
+/// The price of a product for every customer of a tier.
+///
+/// # Fields
+/// - `product_id`: The ID of the product.
+/// - `tier`: The customer tier the price applies to.
+/// - `price`: The price of one unit in cents.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::models::{CustomerTier, TierPrice};
+///
+/// let wholesale_soap = TierPrice { product_id: 1001, tier: CustomerTier::Wholesale, price: 9500 };
+///
+/// assert_eq!(wholesale_soap.price, 9500);
+/// ```
+///
+/// # Notes
+/// - A product without a price for a tier is sold to that tier at `Product.price`.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct TierPrice {
+    pub product_id: i32,
+    pub tier: CustomerTier,
+    pub price: i64,
+}
+
+/// The price of a product for a single customer, overriding their tier's price.
+///
+/// # Fields
+/// - `customer_id`: The ID of the customer.
+/// - `product_id`: The ID of the product.
+/// - `price`: The price of one unit in cents.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct CustomerPrice {
+    pub customer_id: i32,
+    pub product_id: i32,
+    pub price: i64,
+}
+
+/// Where an effective price comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceSource {
+    /// The customer's own price for the product.
+    CustomerOverride,
+    /// The price list of the customer's tier.
+    Tier,
+    /// The product's base `price`.
+    Base,
+}
+
+/// The price a customer pays for a product.
+///
+/// # Fields
+/// - `customer_id`: The ID of the customer.
+/// - `product_id`: The ID of the product.
+/// - `tier`: The customer's tier.
+/// - `price`: The price of one unit in cents.
+/// - `source`: Where the price comes from. A customer override wins over the tier's price, which wins over the base price.
+#[derive(Debug, Serialize)]
+pub struct EffectivePrice {
+    pub customer_id: i32,
+    pub product_id: i32,
+    pub tier: CustomerTier,
+    pub price: i64,
+    pub source: PriceSource,
+}
@@ -1,22 +1,28 @@
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use crate::models::{default_base_unit, TaxCategory, DEFAULT_BASE_UNIT};
 
 /// Represents a product in the database.
 ///
 /// # Fields
 /// - `product_id`: Unique identifier for the product. This ID is typically assigned by the database upon insertion.
 /// - `name`: The name of the product.
-/// - `price`: The price of the product in cents.
+/// - `price`: The price of one base unit of the product in cents. Whether it includes VAT depends on the configured pricing mode.
+/// - `tax_category`: How the product is treated for VAT. Defaults to `TaxCategory::Standard`.
+/// - `base_unit`: The unit the product's stock and sales are kept in, such as `piece` or `kg`. Defaults to `DEFAULT_BASE_UNIT`.
+///   Other units are set up as `ProductUnit`s.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use crate::models::Product;
+/// use crate::models::{Product, TaxCategory};
 ///
 /// let product = Product {
 ///     product_id: 1,
 ///     name: "Laptop".to_string(),
 ///     price: 100000, // Price in cents, so 100000 cents is $1000.00
+///     tax_category: TaxCategory::Standard,
+///     base_unit: "piece".to_string(),
 /// };
 ///
 /// assert_eq!(product.name, "Laptop");
@@ -30,6 +36,10 @@ pub struct Product {
     pub product_id: i64,
     pub name: String,
     pub price: i64,
+    #[serde(default)]
+    pub tax_category: TaxCategory,
+    #[serde(default = "default_base_unit")]
+    pub base_unit: String,
 }
 
 /// Constructs a new `Product` instance.
@@ -41,7 +51,7 @@ pub struct Product {
 ///
 /// # Returns
 ///
-/// A `Product` instance with `product_id` set to `0`. This ID is typically generated by the database upon insertion.
+/// A `Product` instance with `product_id` set to `0`, the standard tax category and the default base unit. This ID is typically generated by the database upon insertion.
 ///
 /// # Examples
 ///
@@ -59,10 +69,13 @@ pub struct Product {
 ///
 /// # Notes
 /// - The `product_id` field is initialized to `0` and should be updated with a value assigned by the database after insertion.
+#[allow(dead_code)]
 pub fn build_product(name: String, price: i64) -> Product {
     Product {
         product_id: 0,
         name,
         price,
+        tax_category: TaxCategory::Standard,
+        base_unit: DEFAULT_BASE_UNIT.to_string(),
     }
 }
\ No newline at end of file
@@ -0,0 +1,157 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use crate::models::CustomerTier;
+
+/// How a promotion discounts a sale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "promotion_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PromotionKind {
+    /// `percent_off` percent off every matching line.
+    PercentOff,
+    /// Of every `buy_quantity + free_quantity` units on a matching line, `free_quantity` units are free.
+    BuyXGetY,
+    /// `amount_off` cents off orders totalling at least `min_order_total`.
+    OrderAmountOff,
+}
+
+/// Represents a promotion that discounts sales automatically while it runs.
+///
+/// # Fields
+/// - `promotion_id`: Unique identifier for the promotion, assigned by the database.
+/// - `name`: The name of the promotion, e.g. "Buy 10 crates get 1 free".
+/// - `kind`: How the promotion discounts a sale.
+/// - `product_id`: The product the promotion applies to, or `None` for every product. Must be `None` for order promotions.
+/// - `customer_id`: The only customer the promotion applies to, or `None` for every customer.
+/// - `customer_tier`: The only customer tier the promotion applies to, or `None` for every tier.
+/// - `starts_at`: When the promotion starts. Sales made from this time on qualify.
+/// - `ends_at`: When the promotion ends. Sales made at or after this time no longer qualify.
+/// - `percent_off`: For `PromotionKind::PercentOff`, the percentage taken off, from 1 to 100.
+/// - `buy_quantity`: For `PromotionKind::BuyXGetY`, the units paid for.
+/// - `free_quantity`: For `PromotionKind::BuyXGetY`, the units given free with them.
+/// - `amount_off`: For `PromotionKind::OrderAmountOff`, the amount in cents taken off the order.
+/// - `min_order_total`: For `PromotionKind::OrderAmountOff`, the order total in cents from which the promotion applies.
+/// - `stackable`: Whether the promotion combines with other promotions. A promotion that does not stack is only applied
+///   on its own, and only when it gives a bigger discount than the stackable promotions together.
+/// - `active`: Whether the promotion is switched on. Inactive promotions are never applied.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::models::{build_promotion, PromotionKind};
+/// use chrono::NaiveDateTime;
+///
+/// let starts_at = NaiveDateTime::parse_from_str("2024-08-05 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let ends_at = NaiveDateTime::parse_from_str("2024-08-12 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// let mut promotion = build_promotion("5% off soap this week".to_string(), PromotionKind::PercentOff, starts_at, ends_at);
+/// promotion.product_id = Some(1001);
+/// promotion.percent_off = 5;
+///
+/// assert!(promotion.active);
+/// ```
+///
+/// # Notes
+/// - Fields that do not belong to the promotion's `kind` are left at `0`.
+/// - Promotions are worked out by the server when a sale is recorded or updated; the discount each one gave is kept on
+///   the sales lines, so changing a promotion later does not change past sales.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Promotion {
+    #[serde(default)]
+    pub promotion_id: i64,
+    pub name: String,
+    pub kind: PromotionKind,
+    #[serde(default)]
+    pub product_id: Option<i32>,
+    #[serde(default)]
+    pub customer_id: Option<i32>,
+    #[serde(default)]
+    pub customer_tier: Option<CustomerTier>,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+    #[serde(default)]
+    pub percent_off: i32,
+    #[serde(default)]
+    pub buy_quantity: i32,
+    #[serde(default)]
+    pub free_quantity: i32,
+    #[serde(default)]
+    pub amount_off: i64,
+    #[serde(default)]
+    pub min_order_total: i64,
+    #[serde(default)]
+    pub stackable: bool,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+/// Constructs a new active, non-stackable `Promotion` for every product and customer.
+///
+/// # Arguments
+///
+/// * `name` - The name of the promotion.
+/// * `kind` - How the promotion discounts a sale.
+/// * `starts_at` - When the promotion starts.
+/// * `ends_at` - When the promotion ends.
+///
+/// # Returns
+///
+/// A `Promotion` instance with `promotion_id` set to `0` and the amounts of every kind left at `0`.
+#[allow(dead_code)]
+pub fn build_promotion(name: String, kind: PromotionKind, starts_at: NaiveDateTime, ends_at: NaiveDateTime) -> Promotion {
+    Promotion {
+        promotion_id: 0,
+        name,
+        kind,
+        product_id: None,
+        customer_id: None,
+        customer_tier: None,
+        starts_at,
+        ends_at,
+        percent_off: 0,
+        buy_quantity: 0,
+        free_quantity: 0,
+        amount_off: 0,
+        min_order_total: 0,
+        stackable: false,
+        active: true,
+    }
+}
+
+/// A promotion applied to a sales line.
+///
+/// # Fields
+/// - `promotion_id`: The ID of the promotion.
+/// - `discount`: The amount in cents the promotion took off the line.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AppliedPromotion {
+    pub promotion_id: i64,
+    pub discount: i64,
+}
+
+/// What a promotion has cost in discounts over a period.
+///
+/// # Fields
+/// - `promotion_id`: The ID of the promotion.
+/// - `name`: The name of the promotion.
+/// - `kind`: How the promotion discounts a sale.
+/// - `sales_count`: The number of sales the promotion was applied to.
+/// - `line_count`: The number of sales lines the promotion was applied to.
+/// - `discount_total`: The total amount in cents the promotion took off those sales.
+///
+/// # Notes
+/// - Voided sales are left out. Returned goods are not netted off.
+#[derive(Debug, Serialize, FromRow)]
+pub struct PromotionCost {
+    pub promotion_id: i64,
+    pub name: String,
+    pub kind: PromotionKind,
+    pub sales_count: i64,
+    pub line_count: i64,
+    pub discount_total: i64,
+}
@@ -0,0 +1,270 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use rocket::FromFormField;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use crate::models::LotDetails;
+
+/// Where a purchase order stands.
+///
+/// Stored in the database as the `purchase_order_status` enum type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "purchase_order_status", rename_all = "snake_case")]
+pub enum PurchaseOrderStatus {
+    /// Placed with the supplier; nothing has arrived yet.
+    #[default]
+    #[field(value = "ordered")]
+    Ordered,
+    /// Some goods have arrived, but not every line in full.
+    #[field(value = "partially_received")]
+    PartiallyReceived,
+    /// Every line has arrived in full, or more.
+    #[field(value = "received")]
+    Received,
+    /// Closed short: the rest of a partly received order is no longer expected.
+    #[field(value = "closed")]
+    Closed,
+    /// Called off before anything arrived.
+    #[field(value = "cancelled")]
+    Cancelled,
+}
+
+/// Represents an order for stock placed with a supplier, to be delivered to a store.
+///
+/// # Fields
+/// - `purchase_order_id`: Unique identifier for the order, assigned by the database.
+/// - `supplier_id`: The ID of the supplier the order is placed with.
+/// - `store_id`: The ID of the store the goods are delivered to.
+/// - `status`: Where the order stands. New orders start as ordered.
+/// - `expected_date`: Optional date the goods are expected.
+/// - `note`: Optional free text about the order.
+/// - `ordered_by`: Optional ID of the sales representative who placed the order.
+/// - `ordered_at`: When the order was placed. Set by the server.
+/// - `closed_at`: When the order was received in full, closed short or cancelled. Set by the server.
+/// - `lines`: The products ordered. These are stored in `public.purchase_order_line` and loaded by the service.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "supplier_id": 3,
+///   "store_id": 1,
+///   "expected_date": "2024-09-10",
+///   "ordered_by": 4,
+///   "lines": [{ "product_id": 3, "quantity": 120, "unit_cost": 1250 }, { "product_id": 5, "quantity": 40, "unit_cost": 980 }]
+/// }
+/// ```
+///
+/// # Notes
+/// - Goods are taken into stock with goods-received notes against the order, which may deliver less or more than was
+///   ordered. Each line keeps the quantity received so far.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct PurchaseOrder {
+    #[serde(default)]
+    pub purchase_order_id: i64,
+    pub supplier_id: i32,
+    pub store_id: i32,
+    #[serde(default)]
+    pub status: PurchaseOrderStatus,
+    #[serde(default)]
+    pub expected_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub ordered_by: Option<i32>,
+    #[serde(default)]
+    pub ordered_at: NaiveDateTime,
+    #[serde(default)]
+    pub closed_at: Option<NaiveDateTime>,
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub lines: Vec<PurchaseOrderLine>,
+}
+
+/// A product ordered on a purchase order.
+///
+/// # Fields
+/// - `purchase_order_line_id`: Unique identifier for the line, assigned by the database.
+/// - `purchase_order_id`: The ID of the order this line belongs to.
+/// - `product_id`: The ID of the product ordered.
+/// - `quantity`: The quantity ordered, in the product's base unit.
+/// - `unit_cost`: The agreed cost of one base unit, in cents. Goods received without a cost of their own come in at it.
+/// - `received_quantity`: The quantity received so far against the line.
+/// - `unit`: The unit a client gave `quantity` and `unit_cost` in, if not the base unit, e.g. cartons at a cost per
+///   carton. Both are turned into base units when the order is placed, the cost rounded to the nearest cent.
+///
+/// # Notes
+/// - Only `product_id`, `quantity`, `unit_cost` and `unit` are read from clients when an order is placed.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct PurchaseOrderLine {
+    #[serde(default)]
+    pub purchase_order_line_id: i64,
+    #[serde(default)]
+    pub purchase_order_id: i64,
+    pub product_id: i32,
+    pub quantity: i32,
+    pub unit_cost: i64,
+    #[serde(default)]
+    pub received_quantity: i32,
+    #[sqlx(skip)]
+    #[serde(default, skip_serializing)]
+    pub unit: Option<String>,
+}
+
+/// A delivery of goods against a purchase order, taken into the order's store.
+///
+/// # Fields
+/// - `goods_received_note_id`: Unique identifier for the note, assigned by the database.
+/// - `purchase_order_id`: The ID of the order the goods were delivered against.
+/// - `store_id`: The ID of the store the goods were taken into.
+/// - `delivery_note`: Optional reference of the supplier's delivery note or invoice.
+/// - `note`: Optional free text about the delivery.
+/// - `received_by`: Optional ID of the sales representative who took in the goods.
+/// - `received_at`: When the goods were taken in. Set by the server.
+/// - `lines`: What arrived. These are stored in `public.goods_received_line` and loaded by the service.
+#[derive(Debug, Serialize, FromRow)]
+pub struct GoodsReceivedNote {
+    pub goods_received_note_id: i64,
+    pub purchase_order_id: i64,
+    pub store_id: i32,
+    pub delivery_note: Option<String>,
+    pub note: Option<String>,
+    pub received_by: Option<i32>,
+    pub received_at: NaiveDateTime,
+    #[sqlx(skip)]
+    pub lines: Vec<GoodsReceivedLine>,
+}
+
+/// A product that arrived on a goods-received note.
+///
+/// # Fields
+/// - `goods_received_line_id`: Unique identifier for the line, assigned by the database.
+/// - `goods_received_note_id`: The ID of the note this line belongs to.
+/// - `purchase_order_line_id`: The ID of the order line the goods were delivered against.
+/// - `product_id`: The ID of the product.
+/// - `quantity`: The quantity that arrived.
+/// - `unit_cost`: What each unit actually cost, in cents.
+/// - `lot_number`: The lot the goods were received into, if they came in one.
+#[derive(Debug, Serialize, FromRow)]
+pub struct GoodsReceivedLine {
+    pub goods_received_line_id: i64,
+    pub goods_received_note_id: i64,
+    pub purchase_order_line_id: i64,
+    pub product_id: i32,
+    pub quantity: i32,
+    pub unit_cost: i64,
+    pub lot_number: Option<String>,
+}
+
+/// A delivery to record against a purchase order.
+///
+/// # Fields
+/// - `received_by`: Optional ID of the sales representative who takes in the goods.
+/// - `delivery_note`: Optional reference of the supplier's delivery note or invoice.
+/// - `note`: Optional free text about the delivery.
+/// - `lines`: What arrived. Order lines left out did not arrive in this delivery; an order line that arrived in
+///   several lots is given once per lot.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "received_by": 7,
+///   "delivery_note": "DN-88213",
+///   "lines": [
+///     { "purchase_order_line_id": 31, "quantity": 100 },
+///     { "purchase_order_line_id": 32, "quantity": 44, "unit_cost": 995, "lot": { "lot_number": "B2408-117", "expiry_date": "2024-11-02" } }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct NewGoodsReceivedNote {
+    #[serde(default)]
+    pub received_by: Option<i32>,
+    #[serde(default)]
+    pub delivery_note: Option<String>,
+    #[serde(default)]
+    pub note: Option<String>,
+    pub lines: Vec<NewGoodsReceivedLine>,
+}
+
+/// The quantity of an order line that arrived in a delivery.
+///
+/// # Fields
+/// - `purchase_order_line_id`: The ID of the order line.
+/// - `quantity`: The quantity that arrived. May take the line past what was ordered.
+/// - `unit_cost`: What each unit actually cost, in cents. Defaults to the order line's agreed cost.
+/// - `lot`: The batch the goods came in, with its manufacture and expiry dates, for goods that are tracked by lot.
+/// - `unit`: The unit `quantity` and `unit_cost` are given in, if not the product's base unit. Both are turned into base
+///   units before the goods are taken into stock.
+#[derive(Debug, Deserialize)]
+pub struct NewGoodsReceivedLine {
+    pub purchase_order_line_id: i64,
+    pub quantity: i32,
+    #[serde(default)]
+    pub unit_cost: Option<i64>,
+    #[serde(default)]
+    pub lot: Option<LotDetails>,
+    #[serde(default)]
+    pub unit: Option<String>,
+}
+
+/// How much of an order line has arrived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FulfilmentStatus {
+    /// Nothing has arrived.
+    Pending,
+    /// Less than was ordered has arrived.
+    Partial,
+    /// Exactly what was ordered has arrived.
+    Complete,
+    /// More than was ordered has arrived.
+    Over,
+}
+
+/// What was ordered on a purchase order line against what arrived.
+///
+/// # Fields
+/// - `purchase_order_line_id`: The ID of the order line.
+/// - `product_id`: The ID of the product.
+/// - `ordered_quantity`: The quantity ordered.
+/// - `received_quantity`: The quantity received so far.
+/// - `outstanding_quantity`: The quantity still to arrive; `0` once the line is complete.
+/// - `over_delivered_quantity`: The quantity received beyond what was ordered.
+/// - `ordered_value`: The quantity ordered at the agreed unit cost, in cents.
+/// - `received_value`: What the goods received actually cost, in cents.
+/// - `status`: How much of the line has arrived.
+#[derive(Debug, Serialize)]
+pub struct PurchaseOrderLineFulfilment {
+    pub purchase_order_line_id: i64,
+    pub product_id: i32,
+    pub ordered_quantity: i32,
+    pub received_quantity: i32,
+    pub outstanding_quantity: i32,
+    pub over_delivered_quantity: i32,
+    pub ordered_value: i64,
+    pub received_value: i64,
+    pub status: FulfilmentStatus,
+}
+
+/// The deliveries against a purchase order, line by line.
+///
+/// # Fields
+/// - `purchase_order_id`, `supplier_id`, `store_id`, `status`, `expected_date`: As on the `PurchaseOrder`.
+/// - `deliveries`: The number of goods-received notes recorded against the order.
+/// - `lines`: What was ordered and what arrived on each line.
+/// - `ordered_value`: The value of the order at the agreed unit costs, in cents.
+/// - `received_value`: What the goods received actually cost, in cents.
+#[derive(Debug, Serialize)]
+pub struct PurchaseOrderFulfilment {
+    pub purchase_order_id: i64,
+    pub supplier_id: i32,
+    pub store_id: i32,
+    pub status: PurchaseOrderStatus,
+    pub expected_date: Option<NaiveDate>,
+    pub deliveries: i64,
+    pub lines: Vec<PurchaseOrderLineFulfilment>,
+    pub ordered_value: i64,
+    pub received_value: i64,
+}
@@ -0,0 +1,207 @@
+use chrono::NaiveDateTime;
+use rocket::FromFormField;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use crate::models::{PaymentMode, SalesLine, TaxCategory};
+
+/// Where a quotation stands with the customer.
+///
+/// Stored in the database as the `quotation_status` enum type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "quotation_status", rename_all = "snake_case")]
+pub enum QuotationStatus {
+    /// Being prepared. Editing a sent quotation brings it back to draft.
+    #[default]
+    #[field(value = "draft")]
+    Draft,
+    /// Given to the customer.
+    #[field(value = "sent")]
+    Sent,
+    /// Taken up by the customer. Only accepted quotations can be converted into a sale.
+    #[field(value = "accepted")]
+    Accepted,
+    /// Not taken up before `valid_until`. Set by the server, never by clients.
+    #[field(value = "expired")]
+    Expired,
+    /// Turned down by the customer.
+    #[field(value = "rejected")]
+    Rejected,
+}
+
+impl QuotationStatus {
+    /// Whether a client may move a quotation from this status to `to`.
+    ///
+    /// Drafts can be sent, drafts and sent quotations accepted, and anything not yet expired or rejected turned down.
+    /// Nothing moves out of `Expired` or `Rejected`.
+    pub fn can_change_to(self, to: QuotationStatus) -> bool {
+        matches!(
+            (self, to),
+            (QuotationStatus::Draft, QuotationStatus::Sent)
+                | (QuotationStatus::Draft | QuotationStatus::Sent, QuotationStatus::Accepted)
+                | (QuotationStatus::Draft | QuotationStatus::Sent | QuotationStatus::Accepted, QuotationStatus::Rejected)
+        )
+    }
+}
+
+/// Represents a price quotation given to a customer before they commit to a purchase.
+///
+/// # Fields
+/// - `quotation_id`: Unique identifier for the quotation, assigned by the database.
+/// - `customer_id`: The ID of the customer the quotation is for.
+/// - `store_id`: The ID of the store the goods would be sold from.
+/// - `sales_rep_id`: Optional ID of the sales representative who prepared the quotation.
+/// - `sales_pair_id`: Optional ID of the pair of sales representatives who prepared the quotation.
+/// - `quoted_at`: When the quotation was last priced. Set by the server.
+/// - `valid_until`: The last moment the customer can accept the quotation. Draft and sent quotations past it expire.
+/// - `status`: Where the quotation stands. New quotations start as drafts.
+/// - `total_price`: The quoted total in cents, VAT included. Computed by the server.
+/// - `sales_id`: The ID of the sale the quotation was converted into, if any. A quotation converts at most once.
+/// - `lines`: The products quoted. These are stored in `public.quotation_line` and loaded by the service.
+///
+/// # Notes
+/// - Lines are priced the way sales are, from the customer's prices, the promotions running at `quoted_at` and VAT.
+/// - Stock is not reserved by a quotation; it is checked when the quotation is converted into a sale.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Quotation {
+    #[serde(default)]
+    pub quotation_id: i64,
+    pub customer_id: i32,
+    pub store_id: i32,
+    #[serde(default)]
+    pub sales_rep_id: Option<i32>,
+    #[serde(default)]
+    pub sales_pair_id: Option<i32>,
+    #[serde(default)]
+    pub quoted_at: NaiveDateTime,
+    pub valid_until: NaiveDateTime,
+    #[serde(default)]
+    pub status: QuotationStatus,
+    #[serde(default)]
+    pub total_price: i64,
+    #[serde(default)]
+    pub sales_id: Option<i64>,
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub lines: Vec<QuotationLine>,
+}
+
+/// A product quoted on a quotation.
+///
+/// # Fields
+/// - `quotation_line_id`: Unique identifier for the line, assigned by the database.
+/// - `quotation_id`: The ID of the quotation this line belongs to.
+/// - `product_id`: The ID of the product quoted.
+/// - `product_quantity`: The quantity quoted.
+/// - `unit_price`: The customer's price of one unit in cents when the quotation was priced.
+/// - `discount`: An optional manual discount in cents taken off this line. A non-zero discount requires a `discount_reason`.
+/// - `discount_reason`: Why the line was discounted.
+/// - `promotion_discount`: The amount in cents running promotions took off this line.
+/// - `tax_category`: The product's tax category.
+/// - `tax_rate`: The VAT rate in basis points.
+/// - `net_price`: The amount of this line in cents before VAT.
+/// - `tax_amount`: The VAT on this line in cents.
+/// - `total_price`: The total price of this line in cents, VAT included.
+///
+/// # Notes
+/// - Only `product_id`, `product_quantity`, `discount` and `discount_reason` are read from clients; the rest is computed
+///   by the server.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct QuotationLine {
+    #[serde(default)]
+    pub quotation_line_id: i64,
+    #[serde(default)]
+    pub quotation_id: i64,
+    pub product_id: i32,
+    pub product_quantity: i32,
+    #[serde(default)]
+    pub unit_price: i64,
+    #[serde(default)]
+    pub discount: i64,
+    #[serde(default)]
+    pub discount_reason: Option<String>,
+    #[serde(default)]
+    pub promotion_discount: i64,
+    #[serde(default)]
+    pub tax_category: TaxCategory,
+    #[serde(default)]
+    pub tax_rate: i32,
+    #[serde(default)]
+    pub net_price: i64,
+    #[serde(default)]
+    pub tax_amount: i64,
+    #[serde(default)]
+    pub total_price: i64,
+}
+
+impl From<SalesLine> for QuotationLine {
+    fn from(line: SalesLine) -> Self {
+        QuotationLine {
+            quotation_line_id: 0,
+            quotation_id: 0,
+            product_id: line.product_id,
+            product_quantity: line.product_quantity,
+            unit_price: line.unit_price,
+            discount: line.discount,
+            discount_reason: line.discount_reason,
+            promotion_discount: line.promotion_discount,
+            tax_category: line.tax_category,
+            tax_rate: line.tax_rate,
+            net_price: line.net_price,
+            tax_amount: line.tax_amount,
+            total_price: line.total_price,
+        }
+    }
+}
+
+/// A change of a quotation's status.
+///
+/// # Fields
+/// - `status`: The new status. Quotations can be sent from draft, accepted or rejected from draft or sent, and an
+///   accepted quotation that has not been converted can still be rejected.
+#[derive(Debug, Deserialize)]
+pub struct QuotationStatusChange {
+    pub status: QuotationStatus,
+}
+
+/// How an accepted quotation is turned into a sale.
+///
+/// # Fields
+/// - `sales_time`: When the sale is made. Defaults to now.
+/// - `payment_mode`: How the sale is paid for. Defaults to `PaymentMode::Cash`.
+/// - `amount_paid`: The amount in cents paid at the time of sale. Only read for `PaymentMode::Partial`.
+/// - `accept_current_prices`: When `true`, the sale is made at the customer's current prices even if they differ from the
+///   quoted ones. Otherwise a change of price since the quotation was priced stops the conversion.
+#[derive(Debug, Deserialize)]
+pub struct QuotationConversion {
+    #[serde(default)]
+    pub sales_time: Option<NaiveDateTime>,
+    #[serde(default)]
+    pub payment_mode: PaymentMode,
+    #[serde(default)]
+    pub amount_paid: i64,
+    #[serde(default)]
+    pub accept_current_prices: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QuotationStatus::{self, Accepted, Draft, Expired, Rejected, Sent};
+
+    #[test]
+    fn quotations_move_forward_only() {
+        let allowed = [(Draft, Sent), (Draft, Accepted), (Sent, Accepted), (Draft, Rejected), (Sent, Rejected), (Accepted, Rejected)];
+        for from in [Draft, Sent, Accepted, Expired, Rejected] {
+            for to in [Draft, Sent, Accepted, Expired, Rejected] {
+                assert_eq!(from.can_change_to(to), allowed.contains(&(from, to)), "{:?} to {:?}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn clients_never_expire_a_quotation() {
+        for from in [Draft, Sent, Accepted] {
+            assert!(!from.can_change_to(QuotationStatus::Expired));
+        }
+    }
+}
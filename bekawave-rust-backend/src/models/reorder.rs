@@ -0,0 +1,81 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// The Postgres notification channel stock alerts are published on when they are raised.
+///
+/// The payload of each notification is the `StockAlert` as JSON. It is sent when the transaction that raised the alert
+/// commits, so listeners never hear of stock changes that were rolled back.
+pub const STOCK_ALERT_CHANNEL: &str = "stock_alert";
+
+/// When a store should reorder a product, and how much.
+///
+/// # Fields
+/// - `store_id`: The ID of the store.
+/// - `product_id`: The ID of the product.
+/// - `reorder_level`: The quantity below which the store's stock of the product is low.
+/// - `reorder_quantity`: How much to order when the stock falls below `reorder_level`.
+///
+/// # Examples
+///
+/// ```json
+/// { "store_id": 2, "product_id": 3, "reorder_level": 20, "reorder_quantity": 48 }
+/// ```
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct ReorderLevel {
+    pub store_id: i32,
+    pub product_id: i32,
+    pub reorder_level: i32,
+    pub reorder_quantity: i32,
+}
+
+/// A store's stock of a product that is below its reorder level.
+///
+/// # Fields
+/// - `store_id`: The ID of the store.
+/// - `product_id`: The ID of the product.
+/// - `quantity`: The quantity the store holds.
+/// - `incoming`: The quantity on its way to the store in dispatched transfers.
+/// - `reorder_level`: The quantity below which the stock is low.
+/// - `reorder_quantity`: How much to order when the stock falls below `reorder_level`.
+/// - `suggested_quantity`: How much to order now: enough to bring what the store holds and has coming to
+///   `reorder_level + reorder_quantity`, so a store that fell far below its level also makes up the shortfall.
+#[derive(Debug, Serialize, FromRow)]
+pub struct LowStock {
+    pub store_id: i32,
+    pub product_id: i32,
+    pub quantity: i32,
+    pub incoming: i32,
+    pub reorder_level: i32,
+    pub reorder_quantity: i32,
+    pub suggested_quantity: i32,
+}
+
+/// Raised when a store's stock of a product falls below its reorder level.
+///
+/// An alert is raised by the stock movement that crosses the level, in the same transaction, and is published on
+/// `STOCK_ALERT_CHANNEL` when that transaction commits. Stock that stays below the level raises no further alerts until
+/// it has been brought back up to the level.
+///
+/// # Fields
+/// - `alert_id`: Unique identifier for the alert, assigned by the database.
+/// - `store_id`: The ID of the store.
+/// - `product_id`: The ID of the product.
+/// - `movement_id`: The ID of the stock movement that took the stock below the level.
+/// - `quantity`: The quantity the store held after that movement.
+/// - `reorder_level`: The reorder level that was crossed.
+/// - `suggested_quantity`: How much to order, worked out as for `LowStock` when the alert was raised.
+/// - `raised_at`: When the alert was raised.
+/// - `acknowledged_at`: When someone took the alert in hand, if anyone has.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct StockAlert {
+    pub alert_id: i64,
+    pub store_id: i32,
+    pub product_id: i32,
+    pub movement_id: i64,
+    pub quantity: i32,
+    pub reorder_level: i32,
+    pub suggested_quantity: i32,
+    pub raised_at: NaiveDateTime,
+    pub acknowledged_at: Option<NaiveDateTime>,
+}
@@ -1,36 +1,71 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use crate::models::SalesLine;
 
-/// Represents a sales transaction in the database.
+/// How a sales order is paid for.
+///
+/// Stored in the database as the `payment_mode` enum type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "payment_mode", rename_all = "snake_case")]
+pub enum PaymentMode {
+    /// The whole order is paid at the time of sale.
+    #[default]
+    Cash,
+    /// Nothing is paid at the time of sale; the whole order becomes a debt.
+    Credit,
+    /// Part of the order is paid at the time of sale; the rest becomes a debt.
+    Partial,
+}
+
+/// Represents a sales order in the database.
+///
+/// A sales order is the header of a sale (who bought, who sold, where and when) together with the
+/// line items describing each product sold on it.
 ///
 /// # Fields
 /// - `sales_id`: Unique identifier for the sales transaction. This ID is typically assigned by the database upon insertion.
+/// - `invoice_number`: The store's gap-free invoice number for the sale, e.g. `NRB-2026-000123`. Assigned by the server when the
+///   sale is created and never changed afterwards.
 /// - `customer_id`: The ID of the customer who made the purchase.
 /// - `sales_pair_id`: Optional ID representing a pair of sales representatives involved in the transaction. Can be `None` if not applicable.
 /// - `sales_rep_id`: Optional ID of the sales representative who handled the transaction. Can be `None` if not applicable.
-/// - `total_price`: The total price of the sales transaction in cents.
+/// - `store_id`: The ID of the store the sale was made from. Its stock is drawn down by the sale and its revenue includes the sale.
+/// - `total_price`: The total price of the sales order in cents, i.e. the sum of its line totals. Clients send the total they
+///   expect to charge and the server checks it against the one it computes from the customer's current prices.
 /// - `sales_time`: The date and time when the sales transaction occurred.
-/// - `product_id`: The ID of the product sold in this transaction.
-/// - `product_quantity`: The quantity of the product sold.
+/// - `payment_mode`: How the order is paid for. Defaults to `PaymentMode::Cash`.
+/// - `amount_paid`: The amount in cents paid at the time of sale. Only read from clients for `PaymentMode::Partial`;
+///   the unpaid remainder of a credit or partial sale is recorded as a `Debt` linked to the order.
+/// - `voided_at`: When the order was voided, or `None` if it stands. Voided orders are kept for the audit trail.
+/// - `voided_by`: Who voided the order.
+/// - `void_reason`: Why the order was voided.
+/// - `lines`: The line items of the order. These are stored in `public.sales_line` and loaded by the service.
 ///
 /// # Examples
 ///
 /// ```rust
-/// use crate::models::Sales;
+/// use crate::models::{PaymentMode, Sales};
 /// use chrono::NaiveDateTime;
 ///
 /// let sales_time = NaiveDateTime::parse_from_str("2024-08-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
 ///
 /// let sales = Sales {
 ///     sales_id: 1,
+///     invoice_number: Some("NRB-2024-000001".to_string()),
 ///     customer_id: 123,
 ///     sales_pair_id: Some(456),
 ///     sales_rep_id: Some(789),
+///     store_id: 1,
 ///     total_price: 29999,
 ///     sales_time,
-///     product_id: 1001,
-///     product_quantity: 3,
+///     payment_mode: PaymentMode::Cash,
+///     amount_paid: 29999,
+///     voided_at: None,
+///     voided_by: None,
+///     void_reason: None,
+///     lines: vec![build_sales_line(1001, 3, 1, Some("Rounding for regular customer".to_string()))],
 /// };
 ///
 /// assert_eq!(sales.customer_id, 123);
@@ -42,13 +77,27 @@ use sqlx::FromRow;
 #[derive(Debug, Serialize, Deserialize, FromRow)]
 pub struct Sales {
     pub sales_id: i64,
+    #[serde(default)]
+    pub invoice_number: Option<String>,
     pub customer_id: i32,
     pub sales_pair_id: Option<i32>,
     pub sales_rep_id: Option<i32>,
-    pub total_price: i32,
+    pub store_id: i32,
+    pub total_price: i64,
     pub sales_time: NaiveDateTime,
-    pub product_id: i32,
-    pub product_quantity: i32,
+    #[serde(default)]
+    pub payment_mode: PaymentMode,
+    #[serde(default)]
+    pub amount_paid: i64,
+    #[serde(default)]
+    pub voided_at: Option<NaiveDateTime>,
+    #[serde(default)]
+    pub voided_by: Option<String>,
+    #[serde(default)]
+    pub void_reason: Option<String>,
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub lines: Vec<SalesLine>,
 }
 
 /// Constructs a new `Sales` instance.
@@ -58,10 +107,12 @@ pub struct Sales {
 /// * `customer_id` - The ID of the customer making the purchase.
 /// * `sales_pair_id` - Optional ID of a pair of sales representatives involved in the transaction.
 /// * `sales_rep_id` - Optional ID of the sales representative who handled the transaction.
-/// * `total_price` - The total price of the sales transaction in cents.
+/// * `store_id` - The ID of the store the sale was made from.
 /// * `sales_time` - The date and time when the sales transaction occurred.
-/// * `product_id` - The ID of the product sold in this transaction.
-/// * `product_quantity` - The quantity of the product sold.
+/// * `total_price` - The total price in cents the client expects the order to come to.
+/// * `payment_mode` - How the order is paid for.
+/// * `amount_paid` - The amount in cents paid at the time of sale.
+/// * `lines` - The line items of the order.
 ///
 /// # Returns
 ///
@@ -79,10 +130,12 @@ pub struct Sales {
 ///     123,
 ///     Some(456),
 ///     Some(789),
+///     1,
 ///     29999,
 ///     sales_time,
-///     1001,
-///     3
+///     PaymentMode::Partial,
+///     10000,
+///     vec![build_sales_line(1001, 3, 1, Some("Rounding for regular customer".to_string()))],
 /// );
 ///
 /// assert_eq!(sales.customer_id, 123);
@@ -91,15 +144,33 @@ pub struct Sales {
 ///
 /// # Notes
 /// - The `sales_id` field is initialized to `0` and should be updated with a value assigned by the database after insertion.
-pub fn build_sales(customer_id: i32, sales_pair_id: Option<i32>, sales_rep_id: Option<i32>, total_price: i32, sales_time: NaiveDateTime, product_id: i32, product_quantity: i32) -> Sales {
+#[allow(dead_code, clippy::too_many_arguments)]
+pub fn build_sales(customer_id: i32, sales_pair_id: Option<i32>, sales_rep_id: Option<i32>, store_id: i32, total_price: i64, sales_time: NaiveDateTime, payment_mode: PaymentMode, amount_paid: i64, lines: Vec<SalesLine>) -> Sales {
     Sales {
         sales_id: 0,
+        invoice_number: None,
         customer_id,
         sales_pair_id,
         sales_rep_id,
+        store_id,
         total_price,
         sales_time,
-        product_id,
-        product_quantity,
+        payment_mode,
+        amount_paid,
+        voided_at: None,
+        voided_by: None,
+        void_reason: None,
+        lines,
     }
+}
+
+/// The details given when voiding a sales order.
+///
+/// # Fields
+/// - `voided_by`: Who is voiding the order.
+/// - `reason`: Why the order is voided.
+#[derive(Debug, Deserialize)]
+pub struct SalesVoid {
+    pub voided_by: String,
+    pub reason: String,
 }
\ No newline at end of file
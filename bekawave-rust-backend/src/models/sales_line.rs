@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use crate::models::{AppliedPromotion, TaxCategory};
+
+/// Represents a single line item of a sales order.
+///
+/// # Fields
+/// - `sales_line_id`: Unique identifier for the line item. This ID is typically assigned by the database upon insertion.
+/// - `sales_id`: The ID of the sales order this line belongs to.
+/// - `product_id`: The ID of the product sold on this line.
+/// - `product_quantity`: The quantity of the product sold, in the product's base unit.
+/// - `unit`: The unit a client gave `product_quantity` in, if not the base unit. The quantity is turned into base units
+///   when the line is priced, and only base units are stored.
+/// - `unit_price`: The price of one unit in cents, taken from the customer's effective price when the sale is recorded.
+/// - `discount`: An optional manual discount in cents taken off this line. A non-zero discount requires a `discount_reason`.
+/// - `discount_reason`: Why the line was discounted, recorded as the override reason.
+/// - `promotion_discount`: The amount in cents running promotions took off this line.
+/// - `tax_category`: The product's tax category when the line was priced.
+/// - `tax_rate`: The VAT rate in basis points the line was charged at, e.g. `1600` for 16%.
+/// - `net_price`: The amount of this line in cents before VAT.
+/// - `tax_amount`: The VAT on this line in cents.
+/// - `total_price`: The total price of this line in cents, VAT included, i.e. `net_price + tax_amount`. With tax-inclusive
+///   prices this is `unit_price * product_quantity - promotion_discount - discount`; otherwise VAT is added to that amount.
+/// - `promotions`: The promotions applied to this line and what each took off. These are stored in `public.sales_line_promotion`.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::models::{SalesLine, TaxCategory};
+///
+/// let line = SalesLine {
+///     sales_line_id: 1,
+///     sales_id: 10,
+///     product_id: 1001,
+///     product_quantity: 3,
+///     unit_price: 10000,
+///     discount: 1,
+///     discount_reason: Some("Rounding for regular customer".to_string()),
+///     promotion_discount: 0,
+///     tax_category: TaxCategory::Standard,
+///     tax_rate: 1600,
+///     net_price: 25861,
+///     tax_amount: 4138,
+///     total_price: 29999,
+///     promotions: vec![],
+///     unit: None,
+/// };
+///
+/// assert_eq!(line.product_id, 1001);
+/// assert_eq!(line.total_price, 29999);
+/// ```
+///
+/// # Notes
+/// - The `sales_line_id` and `sales_id` fields are initialized to `0` in the `build_sales_line` function and are assigned when the order is inserted.
+/// - `unit_price`, `promotion_discount`, the tax fields, `total_price` and `promotions` are computed by the server; values sent by a client are ignored.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct SalesLine {
+    #[serde(default)]
+    pub sales_line_id: i64,
+    #[serde(default)]
+    pub sales_id: i64,
+    pub product_id: i32,
+    pub product_quantity: i32,
+    #[serde(default)]
+    pub unit_price: i64,
+    #[serde(default)]
+    pub discount: i64,
+    #[serde(default)]
+    pub discount_reason: Option<String>,
+    #[serde(default)]
+    pub promotion_discount: i64,
+    #[serde(default)]
+    pub tax_category: TaxCategory,
+    #[serde(default)]
+    pub tax_rate: i32,
+    #[serde(default)]
+    pub net_price: i64,
+    #[serde(default)]
+    pub tax_amount: i64,
+    #[serde(default)]
+    pub total_price: i64,
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub promotions: Vec<AppliedPromotion>,
+    #[sqlx(skip)]
+    #[serde(default, skip_serializing)]
+    pub unit: Option<String>,
+}
+
+/// Constructs a new `SalesLine` instance.
+///
+/// # Arguments
+///
+/// * `product_id` - The ID of the product sold on this line.
+/// * `product_quantity` - The quantity of the product sold.
+/// * `discount` - The discount in cents taken off the line, `0` for none.
+/// * `discount_reason` - Why the line was discounted. Required when `discount` is not `0`.
+///
+/// # Returns
+///
+/// A `SalesLine` instance with `sales_line_id` and `sales_id` set to `0`. `unit_price`, `promotion_discount`, the tax amounts and `total_price` are left at `0`
+/// until the line is priced by the `SalesService`.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::models::SalesLine;
+///
+/// let line = build_sales_line(1001, 3, 0, None);
+///
+/// assert_eq!(line.product_quantity, 3);
+/// ```
+pub fn build_sales_line(product_id: i32, product_quantity: i32, discount: i64, discount_reason: Option<String>) -> SalesLine {
+    SalesLine {
+        sales_line_id: 0,
+        sales_id: 0,
+        product_id,
+        product_quantity,
+        unit_price: 0,
+        discount,
+        discount_reason,
+        promotion_discount: 0,
+        tax_category: TaxCategory::Standard,
+        tax_rate: 0,
+        net_price: 0,
+        tax_amount: 0,
+        total_price: 0,
+        promotions: Vec::new(),
+        unit: None,
+    }
+}
@@ -67,6 +67,7 @@ pub struct SalesPair {
 ///
 /// # Notes
 /// - The `sales_pair_id` field is initialized to `0` and should be updated with a value assigned by the database after insertion.
+#[allow(dead_code)]
 pub fn build_sales_rep_pair(sales_rep_id_one: i32, sales_rep_id_two: i32, paired_date: NaiveDateTime) -> SalesPair {
     SalesPair {
         sales_pair_id: 0,
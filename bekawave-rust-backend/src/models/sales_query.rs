@@ -0,0 +1,79 @@
+use rocket::{FromForm, FromFormField};
+use serde::Serialize;
+use crate::models::Sales;
+
+/// The order in which sales orders are listed.
+///
+/// Ties are broken by `sales_id` in the same direction, so pages are stable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, FromFormField)]
+pub enum SalesSort {
+    /// Oldest sale first.
+    #[field(value = "time_asc")]
+    TimeAsc,
+    /// Newest sale first.
+    #[default]
+    #[field(value = "time_desc")]
+    TimeDesc,
+    /// Cheapest order first.
+    #[field(value = "total_asc")]
+    TotalAsc,
+    /// Most expensive order first.
+    #[field(value = "total_desc")]
+    TotalDesc,
+}
+
+/// Filters, sort order and page of a sales listing, read from the query string.
+///
+/// # Fields
+/// - `from`: Only sales made at or after this time. Either a date (`2024-08-01`) or a date and time (`2024-08-01T12:00:00`).
+/// - `to`: Only sales made at or before this time. A bare date includes the whole day.
+/// - `customer_id`: Only sales to this customer.
+/// - `sales_rep_id`: Only sales handled by this sales representative.
+/// - `sales_pair_id`: Only sales handled by this pair of sales representatives.
+/// - `product_id`: Only sales with at least one line for this product.
+/// - `store_id`: Only sales made from this store.
+/// - `sort`: The order of the results. Defaults to `SalesSort::TimeDesc`.
+/// - `limit`: The maximum number of sales per page. Defaults to `SALES_PAGE_DEFAULT_LIMIT` and is capped at `SALES_PAGE_MAX_LIMIT`.
+/// - `offset`: The number of sales to skip. Defaults to `0`.
+///
+/// # Examples
+///
+/// ```text
+/// GET /sales/get-all?from=2024-08-01&to=2024-08-31&store_id=1&sort=total_desc&limit=20&offset=40
+/// ```
+#[derive(Debug, Default, FromForm)]
+pub struct SalesQuery {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub customer_id: Option<i32>,
+    pub sales_rep_id: Option<i32>,
+    pub sales_pair_id: Option<i32>,
+    pub product_id: Option<i32>,
+    pub store_id: Option<i32>,
+    pub sort: Option<SalesSort>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// The number of sales returned per page when no `limit` is given.
+pub const SALES_PAGE_DEFAULT_LIMIT: i64 = 50;
+
+/// The largest `limit` a client may ask for.
+pub const SALES_PAGE_MAX_LIMIT: i64 = 500;
+
+/// One page of a sales listing.
+///
+/// # Fields
+/// - `sales`: The sales orders on this page, with their line items.
+/// - `total_count`: The number of sales matching the filters, over all pages.
+/// - `limit`: The page size that was applied.
+/// - `offset`: The offset of this page.
+/// - `next_offset`: The `offset` to request for the next page, or `None` if this is the last page.
+#[derive(Debug, Serialize)]
+pub struct SalesPage {
+    pub sales: Vec<Sales>,
+    pub total_count: i64,
+    pub limit: i64,
+    pub offset: i64,
+    pub next_offset: Option<i64>,
+}
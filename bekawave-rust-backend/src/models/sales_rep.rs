@@ -56,6 +56,7 @@ pub struct SalesRep {
 ///
 /// # Notes
 /// - The `sales_rep_id` field is initialized to `0` and should be updated with a value assigned by the database after insertion.
+#[allow(dead_code)]
 pub fn build_sales_rep(name: String, phone_no: String) -> SalesRep {
     SalesRep {
         sales_rep_id: 0,
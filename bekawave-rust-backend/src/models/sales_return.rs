@@ -0,0 +1,172 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Why a customer brought goods back.
+///
+/// Stored in the database as the `return_reason` enum type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "return_reason", rename_all = "snake_case")]
+pub enum ReturnReason {
+    Damaged,
+    WrongItem,
+    Expired,
+}
+
+impl ReturnReason {
+    /// Whether goods returned for this reason can be put back on the shelf.
+    ///
+    /// Only goods returned as the wrong item are sellable; damaged and expired goods are written off.
+    pub fn is_sellable(&self) -> bool {
+        matches!(self, ReturnReason::WrongItem)
+    }
+}
+
+/// How the value of returned goods is given back to the customer.
+///
+/// Stored in the database as the `refund_method` enum type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "refund_method", rename_all = "snake_case")]
+pub enum RefundMethod {
+    /// The customer is paid back in cash.
+    Cash,
+    /// The customer's outstanding debt is reduced by cancelling it, in part or in full. Any amount above the outstanding
+    /// debt is paid back in cash.
+    Debt,
+}
+
+/// Represents goods returned by a customer against an earlier sale.
+///
+/// # Fields
+/// - `return_id`: Unique identifier for the return. This ID is typically assigned by the database upon insertion.
+/// - `sales_id`: The ID of the sales order the goods were sold on.
+/// - `customer_id`: The ID of the customer returning the goods, copied from the sale.
+/// - `store_id`: The ID of the store the goods are returned to, copied from the sale.
+/// - `return_time`: The date and time when the goods were returned.
+/// - `refund_method`: How the customer is refunded.
+/// - `cash_refund`: The amount in cents paid back in cash.
+/// - `debt_reduction`: The amount in cents taken off the customer's outstanding debt.
+/// - `lines`: The returned quantities per sales line. These are stored in `public.sales_return_line` and loaded by the service.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::models::{build_sales_return, build_sales_return_line, RefundMethod, ReturnReason};
+/// use chrono::NaiveDateTime;
+///
+/// let return_time = NaiveDateTime::parse_from_str("2024-08-03 09:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// let sales_return = build_sales_return(
+///     10,
+///     return_time,
+///     RefundMethod::Debt,
+///     vec![build_sales_return_line(42, 2, ReturnReason::Damaged)],
+/// );
+///
+/// assert_eq!(sales_return.sales_id, 10);
+/// assert_eq!(sales_return.lines.len(), 1);
+/// ```
+///
+/// # Notes
+/// - `customer_id`, `store_id`, `cash_refund` and `debt_reduction` are filled in by the service from the original sale.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct SalesReturn {
+    #[serde(default)]
+    pub return_id: i64,
+    pub sales_id: i64,
+    #[serde(default)]
+    pub customer_id: i32,
+    #[serde(default)]
+    pub store_id: i32,
+    pub return_time: NaiveDateTime,
+    pub refund_method: RefundMethod,
+    #[serde(default)]
+    pub cash_refund: i64,
+    #[serde(default)]
+    pub debt_reduction: i64,
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub lines: Vec<SalesReturnLine>,
+}
+
+/// Represents the quantity of one sales line that was returned.
+///
+/// # Fields
+/// - `return_line_id`: Unique identifier for the return line.
+/// - `return_id`: The ID of the return this line belongs to.
+/// - `sales_line_id`: The ID of the sales line the goods were sold on.
+/// - `product_id`: The ID of the returned product, copied from the sales line.
+/// - `quantity`: The quantity returned. May be less than the quantity sold.
+/// - `reason`: Why the goods were returned.
+/// - `restocked`: Whether the goods were put back into the store's stock.
+/// - `refund_amount`: The value of the returned goods in cents, at the price they were sold for.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct SalesReturnLine {
+    #[serde(default)]
+    pub return_line_id: i64,
+    #[serde(default)]
+    pub return_id: i64,
+    pub sales_line_id: i64,
+    #[serde(default)]
+    pub product_id: i32,
+    pub quantity: i32,
+    pub reason: ReturnReason,
+    #[serde(default)]
+    pub restocked: bool,
+    #[serde(default)]
+    pub refund_amount: i64,
+}
+
+/// Constructs a new `SalesReturn` instance.
+///
+/// # Arguments
+///
+/// * `sales_id` - The ID of the sales order the goods were sold on.
+/// * `return_time` - The date and time when the goods were returned.
+/// * `refund_method` - How the customer is refunded.
+/// * `lines` - The returned quantities per sales line.
+///
+/// # Returns
+///
+/// A `SalesReturn` instance with `return_id` set to `0`. This ID is typically generated by the database upon insertion.
+#[allow(dead_code)]
+pub fn build_sales_return(sales_id: i64, return_time: NaiveDateTime, refund_method: RefundMethod, lines: Vec<SalesReturnLine>) -> SalesReturn {
+    SalesReturn {
+        return_id: 0,
+        sales_id,
+        customer_id: 0,
+        store_id: 0,
+        return_time,
+        refund_method,
+        cash_refund: 0,
+        debt_reduction: 0,
+        lines,
+    }
+}
+
+/// Constructs a new `SalesReturnLine` instance.
+///
+/// # Arguments
+///
+/// * `sales_line_id` - The ID of the sales line the goods were sold on.
+/// * `quantity` - The quantity returned.
+/// * `reason` - Why the goods were returned.
+///
+/// # Returns
+///
+/// A `SalesReturnLine` instance whose IDs, product, restock flag and refund amount are filled in by the service.
+#[allow(dead_code)]
+pub fn build_sales_return_line(sales_line_id: i64, quantity: i32, reason: ReturnReason) -> SalesReturnLine {
+    SalesReturnLine {
+        return_line_id: 0,
+        return_id: 0,
+        sales_line_id,
+        product_id: 0,
+        quantity,
+        reason,
+        restocked: false,
+        refund_amount: 0,
+    }
+}
@@ -38,10 +38,10 @@ use sqlx::FromRow;
 pub struct Stock {
     pub stock_id: i64,
     pub store_id: i32,
-    pub amount: i32,
+    pub amount: i64,
     pub product_id: i32,
-    pub quantity: i32,
-    pub product_worth: i32,
+    pub quantity: i64,
+    pub product_worth: i64,
 }
 
 /** Constructs a new `Stock` instance.
@@ -71,7 +71,8 @@ assert_eq!(stock.product_worth, 50);
 
 # Notes
 - The `stock_id` field is initialized to `0` and should be updated with a value assigned by the database after insertion. **/
-pub fn build_stock(store_id: i32, amount: i32, product_id: i32, quantity: i32, product_worth: i32) -> Stock {
+#[allow(dead_code)]
+pub fn build_stock(store_id: i32, amount: i64, product_id: i32, quantity: i64, product_worth: i64) -> Stock {
     Stock {
         stock_id: 0,
         store_id,
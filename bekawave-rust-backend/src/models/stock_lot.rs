@@ -0,0 +1,93 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// A batch of a product held by a store, with the dates it was made and expires.
+///
+/// Lots sit under the store's `stock_record` for the product: the record's `quantity` is what the store holds in all,
+/// and each lot says how much of that came from one batch. Stock not held in any lot, such as goods of products that
+/// do not perish or goods held before lots were recorded, is the record's quantity less the quantity of its lots.
+///
+/// Goods received with a lot number go into that lot. Goods leaving the store come out of its lots first expiry first
+/// out, unless the movement names the lot they come from. Goods put back by a changed or voided sale, or arriving on a
+/// transfer, go back into the lots they left; goods customers bring back are held outside any lot.
+///
+/// # Fields
+/// - `lot_id`: Unique identifier for the lot, assigned by the database.
+/// - `stock_id`: The ID of the `stock_record` the lot sits under.
+/// - `store_id`: The ID of the store holding the lot.
+/// - `product_id`: The ID of the product.
+/// - `lot_number`: The batch or lot number printed on the goods. Unique within a stock record.
+/// - `manufacture_date`: When the batch was made, if known.
+/// - `expiry_date`: The last day the goods can be sold, if they expire.
+/// - `quantity`: The quantity of the lot the store holds.
+/// - `received_at`: When the lot was first received into the store.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "lot_id": 41,
+///   "stock_id": 7,
+///   "store_id": 2,
+///   "product_id": 3,
+///   "lot_number": "B2408-117",
+///   "manufacture_date": "2024-08-02",
+///   "expiry_date": "2024-11-02",
+///   "quantity": 36,
+///   "received_at": "2024-08-09T14:20:11"
+/// }
+/// ```
+#[derive(Debug, Serialize, FromRow)]
+pub struct StockLot {
+    pub lot_id: i64,
+    pub stock_id: i64,
+    pub store_id: i32,
+    pub product_id: i32,
+    pub lot_number: String,
+    pub manufacture_date: Option<NaiveDate>,
+    pub expiry_date: Option<NaiveDate>,
+    pub quantity: i32,
+    pub received_at: NaiveDateTime,
+}
+
+/// The lot a stock movement puts goods into or takes them from.
+///
+/// Goods coming in go into the lot with this number, which is created with the given dates if the store does not hold
+/// it yet. Goods going out, such as a write-off of an expired batch, come out of the lot with this number; the dates
+/// are then ignored.
+///
+/// # Examples
+///
+/// ```json
+/// { "lot_number": "B2408-117", "manufacture_date": "2024-08-02", "expiry_date": "2024-11-02" }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct LotDetails {
+    pub lot_number: String,
+    #[serde(default)]
+    pub manufacture_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub expiry_date: Option<NaiveDate>,
+}
+
+/// A lot that expires soon or has expired while the store still holds it.
+///
+/// # Fields
+/// - `lot_id`, `store_id`, `product_id`, `lot_number`, `expiry_date`, `quantity`: As on the `StockLot`.
+/// - `product_name`: The name of the product.
+/// - `days_to_expiry`: Days from today to the expiry date; `0` on the last day the goods can be sold and negative once
+///   they have expired.
+/// - `value`: What the goods in the lot are worth at the stock record's `product_worth`, in cents.
+#[derive(Debug, Serialize, FromRow)]
+pub struct LotExpiry {
+    pub lot_id: i64,
+    pub store_id: i32,
+    pub product_id: i32,
+    pub product_name: String,
+    pub lot_number: String,
+    pub expiry_date: NaiveDate,
+    pub quantity: i32,
+    pub days_to_expiry: i32,
+    pub value: i64,
+}
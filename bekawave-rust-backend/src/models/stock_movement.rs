@@ -0,0 +1,223 @@
+use chrono::NaiveDateTime;
+use rocket::{FromForm, FromFormField};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use crate::models::LotDetails;
+
+/// Why stock moved in or out of a store.
+///
+/// Stored in the database as the `stock_movement_type` enum type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "stock_movement_type", rename_all = "snake_case")]
+pub enum StockMovementType {
+    /// Goods received into the store, e.g. a delivery from a supplier.
+    #[field(value = "receipt")]
+    Receipt,
+    /// Goods sold from the store, or put back when a sale is changed or voided.
+    #[field(value = "sale")]
+    Sale,
+    /// Goods a customer brought back that can be sold again.
+    #[field(value = "return")]
+    Return,
+    /// Goods moved between stores.
+    #[field(value = "transfer")]
+    Transfer,
+    /// A correction made after counting the goods in the store.
+    #[field(value = "adjustment")]
+    Adjustment,
+    /// Goods taken out of stock because they are damaged, expired or lost.
+    #[field(value = "write_off")]
+    WriteOff,
+}
+
+/// One entry of the stock ledger: a change in the quantity of a product held by a store.
+///
+/// The ledger is append-only. Every change to a `stock_record` quantity is recorded here in the same transaction, so
+/// the quantity of a store's stock can always be explained by its movements.
+///
+/// # Fields
+/// - `movement_id`: Unique identifier for the movement, assigned by the database. Movements of a store's stock of a
+///   product are applied in `movement_id` order.
+/// - `store_id`: The ID of the store whose stock moved.
+/// - `product_id`: The ID of the product that moved.
+/// - `movement_type`: Why the stock moved.
+/// - `quantity`: The change in quantity; negative when goods left the store.
+/// - `balance`: The quantity the store held after the movement.
+/// - `reference_type`: The kind of document the movement comes from, e.g. `sales` or `sales_return`. `None` for
+///   movements recorded by hand without a document.
+/// - `reference_id`: The ID of that document.
+/// - `sales_rep_id`: The ID of the sales representative who made the movement, if known.
+/// - `note`: Free text explaining the movement.
+/// - `unit_cost`: What each unit cost, in cents, for goods coming in at a known cost such as a receipt. Goods coming in
+///   without one are costed by the valuation report.
+/// - `moved_at`: When the movement was recorded. Set by the server.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "movement_id": 912,
+///   "store_id": 2,
+///   "product_id": 3,
+///   "movement_type": "sale",
+///   "quantity": -2,
+///   "balance": 36,
+///   "reference_type": "sales",
+///   "reference_id": 4410,
+///   "sales_rep_id": 4,
+///   "note": null,
+///   "unit_cost": null,
+///   "moved_at": "2024-08-01T10:15:02"
+/// }
+/// ```
+#[derive(Debug, Serialize, FromRow)]
+pub struct StockMovement {
+    pub movement_id: i64,
+    pub store_id: i32,
+    pub product_id: i32,
+    pub movement_type: StockMovementType,
+    pub quantity: i32,
+    pub balance: i64,
+    pub reference_type: Option<String>,
+    pub reference_id: Option<i64>,
+    pub sales_rep_id: Option<i32>,
+    pub note: Option<String>,
+    pub unit_cost: Option<i64>,
+    pub moved_at: NaiveDateTime,
+}
+
+/// What a change in stock is recorded as in the ledger: everything about a movement except the quantities.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::models::{StockMovementSource, StockMovementType};
+///
+/// let source = StockMovementSource::new(StockMovementType::Sale)
+///     .reference("sales", 4410)
+///     .by(Some(4));
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct StockMovementSource {
+    pub movement_type: StockMovementType,
+    #[serde(default)]
+    pub reference_type: Option<String>,
+    #[serde(default)]
+    pub reference_id: Option<i64>,
+    #[serde(default)]
+    pub sales_rep_id: Option<i32>,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub unit_cost: Option<i64>,
+    #[serde(default)]
+    pub lot: Option<LotDetails>,
+}
+
+impl StockMovementSource {
+    /// A movement of the given type with no document, sales representative or note.
+    pub fn new(movement_type: StockMovementType) -> Self {
+        StockMovementSource { movement_type, reference_type: None, reference_id: None, sales_rep_id: None, note: None, unit_cost: None, lot: None }
+    }
+
+    /// Sets the document the movement comes from.
+    pub fn reference(mut self, reference_type: &str, reference_id: i64) -> Self {
+        self.reference_type = Some(reference_type.to_string());
+        self.reference_id = Some(reference_id);
+        self
+    }
+
+    /// Sets the sales representative who made the movement.
+    pub fn by(mut self, sales_rep_id: Option<i32>) -> Self {
+        self.sales_rep_id = sales_rep_id;
+        self
+    }
+
+    /// Sets the note explaining the movement.
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    /// Sets what each unit coming in cost, in cents.
+    pub fn cost(mut self, unit_cost: i64) -> Self {
+        self.unit_cost = Some(unit_cost);
+        self
+    }
+
+    /// Sets the lot the goods go into or come out of.
+    pub fn lot(mut self, lot: LotDetails) -> Self {
+        self.lot = Some(lot);
+        self
+    }
+}
+
+/// A movement recorded by hand rather than by a sale, return or other document.
+///
+/// # Fields
+/// - `store_id`: The ID of the store whose stock moves.
+/// - `product_id`: The ID of the product that moves.
+/// - `quantity`: The change in quantity. Positive for a `receipt`, negative for a `write_off`, either for an `adjustment`.
+/// - `source`: The movement type and, optionally, the document, sales representative and note, written next to the
+///   other fields. Only `receipt`, `adjustment` and `write_off` movements can be recorded by hand. A `receipt` must
+///   give its `unit_cost`; an `adjustment` that adds goods may. Any of them may name the `lot` the goods go into or
+///   come out of.
+/// - `unit`: The unit `quantity` and any `unit_cost` are given in, if not the product's base unit. Both are turned into
+///   base units before the movement is recorded.
+///
+/// # Examples
+///
+/// ```json
+/// { "store_id": 2, "product_id": 3, "quantity": 48, "movement_type": "receipt", "unit_cost": 1250 }
+/// { "store_id": 2, "product_id": 3, "quantity": -4, "movement_type": "write_off", "note": "Crushed in transit" }
+/// { "store_id": 2, "product_id": 3, "quantity": -12, "movement_type": "write_off", "lot": { "lot_number": "B2408-117" } }
+/// { "store_id": 2, "product_id": 3, "quantity": 2, "unit": "carton", "movement_type": "receipt", "unit_cost": 30000 }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct NewStockMovement {
+    pub store_id: i32,
+    pub product_id: i32,
+    pub quantity: i32,
+    #[serde(default)]
+    pub unit: Option<String>,
+    #[serde(flatten)]
+    pub source: StockMovementSource,
+}
+
+/// Filters and page of a stock ledger listing, read from the query string.
+///
+/// # Fields
+/// - `store_id`: Only movements of this store's stock.
+/// - `product_id`: Only movements of this product.
+/// - `movement_type`: Only movements of this type.
+/// - `reference_type` and `reference_id`: Only movements from this kind of document, or from this document.
+/// - `from`: Only movements recorded at or after this time. Either a date (`2024-08-01`) or a date and time.
+/// - `to`: Only movements recorded at or before this time. A bare date includes the whole day.
+/// - `limit`: The maximum number of movements returned. Defaults to `STOCK_MOVEMENT_PAGE_DEFAULT_LIMIT` and is capped
+///   at `STOCK_MOVEMENT_PAGE_MAX_LIMIT`.
+/// - `offset`: The number of movements to skip. Defaults to `0`.
+///
+/// # Examples
+///
+/// ```text
+/// GET /stocks/movements?store_id=2&product_id=3&from=2024-08-01&limit=50
+/// ```
+#[derive(Debug, Default, FromForm)]
+pub struct StockMovementQuery {
+    pub store_id: Option<i32>,
+    pub product_id: Option<i32>,
+    pub movement_type: Option<StockMovementType>,
+    pub reference_type: Option<String>,
+    pub reference_id: Option<i64>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// The number of movements returned when no `limit` is given.
+pub const STOCK_MOVEMENT_PAGE_DEFAULT_LIMIT: i64 = 100;
+
+/// The largest `limit` a client may ask for.
+pub const STOCK_MOVEMENT_PAGE_MAX_LIMIT: i64 = 1000;
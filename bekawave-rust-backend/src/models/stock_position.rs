@@ -0,0 +1,124 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use crate::models::{UnitQuantity, ValuationMethod};
+
+/// What a store held of a product at a point in time, rebuilt from the stock ledger.
+///
+/// # Fields
+/// - `store_id`: The ID of the store.
+/// - `product_id`: The ID of the product.
+/// - `product_name`: The name of the product.
+/// - `base_unit`: The product's base unit, which `quantity` and `unit_cost` are in.
+/// - `quantity`: The quantity the store held.
+/// - `unit_cost`: The average cost of each unit held, in cents. `0` when the store held none.
+/// - `value`: What the units held cost, in cents.
+/// - `in_unit`: The quantity in the unit the report was asked for, if the product can be counted in it. Left out
+///   otherwise.
+#[derive(Debug, Serialize)]
+pub struct StockPosition {
+    pub store_id: i32,
+    pub product_id: i32,
+    pub product_name: String,
+    pub base_unit: String,
+    pub quantity: i64,
+    pub unit_cost: i64,
+    pub value: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_unit: Option<UnitQuantity>,
+}
+
+/// The stock held at a point in time, as the inventory page shows the current stock.
+///
+/// # Fields
+/// - `method`: The valuation method the stock was costed with.
+/// - `as_of`: The time the positions were rebuilt at; every movement recorded up to then is taken into account.
+/// - `lines`: What each store held of each product, by store and then product. Stock that was not held is left out.
+/// - `total_quantity`: The quantity held across all lines.
+/// - `total_value`: The value of all lines, in cents.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "method": "weighted_average",
+///   "as_of": "2024-03-31T23:59:59.999999999",
+///   "lines": [{
+///     "store_id": 3, "product_id": 5, "product_name": "Maize flour 2kg", "base_unit": "piece", "quantity": 120, "unit_cost": 1480,
+///     "value": 177600, "in_unit": { "unit": "bale", "factor": 12, "quantity": 10, "remainder": 0 }
+///   }],
+///   "total_quantity": 120,
+///   "total_value": 177600
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct StockPositions {
+    pub method: ValuationMethod,
+    pub as_of: NaiveDateTime,
+    pub lines: Vec<StockPosition>,
+    pub total_quantity: i64,
+    pub total_value: i64,
+}
+
+/// How a store's stock of a product changed between two points in time.
+///
+/// The opening quantity plus the movements of each type equals the closing quantity. Movement quantities are signed
+/// as in the ledger: negative when goods left the store.
+///
+/// # Fields
+/// - `store_id`, `product_id`, `product_name`: The store and product.
+/// - `base_unit`: The product's base unit, which all quantities are in.
+/// - `opening_quantity`, `opening_value`: What the store held at the start, and its value in cents.
+/// - `received`: Goods received, e.g. from suppliers.
+/// - `sold`: Goods sold, net of sales changed or voided.
+/// - `returned`: Goods customers brought back.
+/// - `transferred`: Goods moved in from and out to other stores.
+/// - `adjusted`: Corrections after counting the goods.
+/// - `written_off`: Goods taken out of stock as damaged, expired or lost.
+/// - `closing_quantity`, `closing_value`: What the store held at the end, and its value in cents.
+/// - `quantity_change`, `value_change`: The closing less the opening quantity and value.
+/// - `opening_in_unit`, `closing_in_unit`: The opening and closing quantities in the unit the comparison was asked
+///   for, if the product can be counted in it. Left out otherwise.
+#[derive(Debug, Serialize)]
+pub struct StockPositionChange {
+    pub store_id: i32,
+    pub product_id: i32,
+    pub product_name: String,
+    pub base_unit: String,
+    pub opening_quantity: i64,
+    pub opening_value: i64,
+    pub received: i64,
+    pub sold: i64,
+    pub returned: i64,
+    pub transferred: i64,
+    pub adjusted: i64,
+    pub written_off: i64,
+    pub closing_quantity: i64,
+    pub closing_value: i64,
+    pub quantity_change: i64,
+    pub value_change: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opening_in_unit: Option<UnitQuantity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closing_in_unit: Option<UnitQuantity>,
+}
+
+/// A comparison of the stock held at two points in time.
+///
+/// # Fields
+/// - `method`: The valuation method the stock was costed with.
+/// - `from`: The first point in time.
+/// - `to`: The second point in time.
+/// - `lines`: How each store's stock of each product changed, by store and then product. Stock that was not held at
+///   either time and did not move in between is left out.
+/// - `opening_quantity`, `opening_value`, `closing_quantity`, `closing_value`: The totals of the lines.
+#[derive(Debug, Serialize)]
+pub struct StockPositionComparison {
+    pub method: ValuationMethod,
+    pub from: NaiveDateTime,
+    pub to: NaiveDateTime,
+    pub lines: Vec<StockPositionChange>,
+    pub opening_quantity: i64,
+    pub opening_value: i64,
+    pub closing_quantity: i64,
+    pub closing_value: i64,
+}
@@ -0,0 +1,185 @@
+use chrono::NaiveDateTime;
+use rocket::FromFormField;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Where a stock transfer between stores stands.
+///
+/// Stored in the database as the `transfer_status` enum type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "transfer_status", rename_all = "snake_case")]
+pub enum TransferStatus {
+    /// Asked for; no stock has moved yet.
+    #[default]
+    #[field(value = "requested")]
+    Requested,
+    /// Sent from the source store. The goods have left it and are in transit.
+    #[field(value = "dispatched")]
+    Dispatched,
+    /// Taken in by the destination store.
+    #[field(value = "received")]
+    Received,
+    /// Called off before it was dispatched.
+    #[field(value = "cancelled")]
+    Cancelled,
+}
+
+/// Represents a transfer of stock from one store to another.
+///
+/// # Fields
+/// - `transfer_id`: Unique identifier for the transfer, assigned by the database.
+/// - `from_store_id`: The ID of the store the goods are sent from.
+/// - `to_store_id`: The ID of the store the goods are sent to.
+/// - `status`: Where the transfer stands. New transfers start as requested.
+/// - `note`: Optional free text about the transfer.
+/// - `requested_by`, `dispatched_by`, `received_by`: Optional IDs of the sales representatives who asked for, sent and
+///   took in the goods.
+/// - `requested_at`, `dispatched_at`, `received_at`: When the transfer was asked for, sent and taken in. Set by the server.
+/// - `lines`: The products transferred. These are stored in `public.stock_transfer_line` and loaded by the service.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "from_store_id": 1,
+///   "to_store_id": 2,
+///   "requested_by": 4,
+///   "note": "Restock for the Gilgil branch",
+///   "lines": [{ "product_id": 3, "quantity": 24 }, { "product_id": 5, "quantity": 10 }]
+/// }
+/// ```
+///
+/// # Notes
+/// - Dispatching takes the goods out of the source store and receiving puts what arrived into the destination store,
+///   each recorded in the stock ledger as a `transfer` movement in the same transaction as the status change.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct StockTransfer {
+    #[serde(default)]
+    pub transfer_id: i64,
+    pub from_store_id: i32,
+    pub to_store_id: i32,
+    #[serde(default)]
+    pub status: TransferStatus,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub requested_by: Option<i32>,
+    #[serde(default)]
+    pub dispatched_by: Option<i32>,
+    #[serde(default)]
+    pub received_by: Option<i32>,
+    #[serde(default)]
+    pub requested_at: NaiveDateTime,
+    #[serde(default)]
+    pub dispatched_at: Option<NaiveDateTime>,
+    #[serde(default)]
+    pub received_at: Option<NaiveDateTime>,
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub lines: Vec<StockTransferLine>,
+}
+
+/// A product moved by a stock transfer.
+///
+/// # Fields
+/// - `transfer_line_id`: Unique identifier for the line, assigned by the database.
+/// - `transfer_id`: The ID of the transfer this line belongs to.
+/// - `product_id`: The ID of the product transferred.
+/// - `quantity`: The quantity asked for, and sent when the transfer is dispatched, in the product's base unit.
+/// - `unit_worth`: The worth of one unit at the source store when the goods were sent. Set on dispatch.
+/// - `received_quantity`: The quantity that arrived. Set on receipt.
+/// - `discrepancy_note`: Why the quantity that arrived differs from the quantity sent, e.g. breakage on the road.
+/// - `unit`: The unit a client gave `quantity` in, if not the base unit. The quantity is turned into base units when the
+///   transfer is created, and only base units are stored.
+///
+/// # Notes
+/// - Only `product_id`, `quantity` and `unit` are read from clients when a transfer is created; the rest is set by the server.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct StockTransferLine {
+    #[serde(default)]
+    pub transfer_line_id: i64,
+    #[serde(default)]
+    pub transfer_id: i64,
+    pub product_id: i32,
+    pub quantity: i32,
+    #[serde(default)]
+    pub unit_worth: Option<i32>,
+    #[serde(default)]
+    pub received_quantity: Option<i32>,
+    #[serde(default)]
+    pub discrepancy_note: Option<String>,
+    #[sqlx(skip)]
+    #[serde(default, skip_serializing)]
+    pub unit: Option<String>,
+}
+
+/// Who sends a requested transfer on its way.
+///
+/// # Fields
+/// - `dispatched_by`: Optional ID of the sales representative who sends the goods.
+#[derive(Debug, Default, Deserialize)]
+pub struct TransferDispatch {
+    #[serde(default)]
+    pub dispatched_by: Option<i32>,
+}
+
+/// What arrived at the destination store of a dispatched transfer.
+///
+/// # Fields
+/// - `received_by`: Optional ID of the sales representative who takes in the goods.
+/// - `lines`: The lines that did not arrive as sent. Lines left out are taken as received in full.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "received_by": 7,
+///   "lines": [{ "transfer_line_id": 31, "received_quantity": 22, "discrepancy_note": "Two cartons crushed" }]
+/// }
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct TransferReceipt {
+    #[serde(default)]
+    pub received_by: Option<i32>,
+    #[serde(default)]
+    pub lines: Vec<ReceivedTransferLine>,
+}
+
+/// The quantity of a transfer line that arrived.
+///
+/// # Fields
+/// - `transfer_line_id`: The ID of the line.
+/// - `received_quantity`: The quantity that arrived. May be more or less than was sent.
+/// - `discrepancy_note`: Why it differs from the quantity sent. Required when it does.
+/// - `unit`: The unit `received_quantity` is given in, if not the product's base unit.
+#[derive(Debug, Deserialize)]
+pub struct ReceivedTransferLine {
+    pub transfer_line_id: i64,
+    pub received_quantity: i32,
+    #[serde(default)]
+    pub discrepancy_note: Option<String>,
+    #[serde(default)]
+    pub unit: Option<String>,
+}
+
+/// Goods dispatched from one store to another that have not been received yet.
+///
+/// # Fields
+/// - `transfer_id`: The ID of the transfer carrying the goods.
+/// - `from_store_id`: The ID of the store the goods were sent from.
+/// - `to_store_id`: The ID of the store the goods are going to.
+/// - `product_id`: The ID of the product.
+/// - `quantity`: The quantity on the way.
+/// - `value`: The worth of the goods on the way, at the source store's worth when they were sent.
+/// - `dispatched_at`: When the goods were sent.
+#[derive(Debug, Serialize, FromRow)]
+pub struct GoodsInTransit {
+    pub transfer_id: i64,
+    pub from_store_id: i32,
+    pub to_store_id: i32,
+    pub product_id: i32,
+    pub quantity: i32,
+    pub value: i64,
+    pub dispatched_at: NaiveDateTime,
+}
@@ -0,0 +1,182 @@
+use chrono::NaiveDateTime;
+use rocket::FromFormField;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Where a stocktake stands.
+///
+/// Stored in the database as the `stocktake_status` enum type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type, FromFormField)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "stocktake_status", rename_all = "snake_case")]
+pub enum StocktakeStatus {
+    /// Being counted. Counts can be added or replaced.
+    #[default]
+    #[field(value = "open")]
+    Open,
+    /// Finished; its variances were posted to the store's stock.
+    #[field(value = "closed")]
+    Closed,
+    /// Called off without changing the store's stock.
+    #[field(value = "cancelled")]
+    Cancelled,
+}
+
+/// A physical count of a store's stock.
+///
+/// Opening a stocktake freezes what the store is expected to hold of each product, so the store can keep trading while
+/// it is counted: when the stocktake is closed, the difference between each counted and expected quantity is posted
+/// to the store's stock as an `adjustment`, on top of whatever was sold or received in the meantime.
+///
+/// # Fields
+/// - `stocktake_id`: Unique identifier for the stocktake, assigned by the database.
+/// - `store_id`: The ID of the store being counted. A store has at most one open stocktake.
+/// - `status`: Where the stocktake stands. New stocktakes start open.
+/// - `note`: Optional free text about the stocktake.
+/// - `opened_by`, `closed_by`: Optional IDs of the sales representatives who started and finished the stocktake.
+/// - `opened_at`, `closed_at`: When the stocktake was started and finished or cancelled. Set by the server.
+/// - `lines`: The expected and counted quantity of each product. Loaded by the service.
+///
+/// # Examples
+///
+/// ```json
+/// { "store_id": 2, "opened_by": 4, "note": "August month-end count" }
+/// ```
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Stocktake {
+    #[serde(default)]
+    pub stocktake_id: i64,
+    pub store_id: i32,
+    #[serde(default)]
+    pub status: StocktakeStatus,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub opened_by: Option<i32>,
+    #[serde(default)]
+    pub closed_by: Option<i32>,
+    #[serde(default)]
+    pub opened_at: NaiveDateTime,
+    #[serde(default)]
+    pub closed_at: Option<NaiveDateTime>,
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub lines: Vec<StocktakeLine>,
+}
+
+/// What a store was expected to hold of a product when a stocktake was opened, and what was counted.
+///
+/// # Fields
+/// - `stocktake_line_id`: Unique identifier for the line, assigned by the database.
+/// - `stocktake_id`: The ID of the stocktake this line belongs to.
+/// - `product_id`: The ID of the product.
+/// - `expected_quantity`: The quantity the store held when the stocktake was opened. `0` for a product found in the
+///   count that the store had no stock of.
+/// - `unit_cost`: What each unit cost when the stocktake was opened, in cents, used to value the variance.
+/// - `counted_quantity`: The total of all counts of the product. `None` until it has been counted.
+/// - `variance`: `counted_quantity - expected_quantity`; negative for a shortage. `None` until counted.
+/// - `variance_value`: The variance valued at `unit_cost`, in cents. `None` until counted.
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct StocktakeLine {
+    pub stocktake_line_id: i64,
+    pub stocktake_id: i64,
+    pub product_id: i32,
+    pub expected_quantity: i32,
+    pub unit_cost: i64,
+    pub counted_quantity: Option<i32>,
+    pub variance: Option<i32>,
+    pub variance_value: Option<i64>,
+}
+
+/// One counter's count of a product in a stocktake.
+///
+/// A product's counted quantity is the total of its counts, so several people can each count part of a store, such as
+/// the shop floor and the back room. Counting a product again replaces the counter's earlier count of it.
+///
+/// # Fields
+/// - `count_id`: Unique identifier for the count, assigned by the database.
+/// - `stocktake_id`: The ID of the stocktake.
+/// - `product_id`: The ID of the product counted.
+/// - `quantity`: The quantity counted.
+/// - `counted_by`: Optional ID of the sales representative who counted.
+/// - `note`: Optional free text, e.g. where the goods were counted.
+/// - `counted_at`: When the count was recorded. Set by the server.
+#[derive(Debug, Serialize, FromRow)]
+pub struct StocktakeCount {
+    pub count_id: i64,
+    pub stocktake_id: i64,
+    pub product_id: i32,
+    pub quantity: i32,
+    pub counted_by: Option<i32>,
+    pub note: Option<String>,
+    pub counted_at: NaiveDateTime,
+}
+
+/// Counts of one or more products by one counter. Products left out keep their earlier counts.
+///
+/// # Fields
+/// - `counted_by`: Optional ID of the sales representative who counted.
+/// - `counts`: The quantity counted of each product.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "counted_by": 7,
+///   "counts": [{ "product_id": 3, "quantity": 34, "note": "Back room" }, { "product_id": 5, "quantity": 0 }]
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct StocktakeCounts {
+    #[serde(default)]
+    pub counted_by: Option<i32>,
+    pub counts: Vec<CountedQuantity>,
+}
+
+/// The quantity of a product a counter found.
+#[derive(Debug, Deserialize)]
+pub struct CountedQuantity {
+    pub product_id: i32,
+    pub quantity: i32,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+/// How a stocktake is finished.
+///
+/// # Fields
+/// - `closed_by`: Optional ID of the sales representative who finishes the stocktake.
+/// - `uncounted_as_zero`: Whether products that were expected but never counted are taken as gone. By default they
+///   are left out of the variance and the store's stock of them is not changed, so a store can be counted in part.
+#[derive(Debug, Default, Deserialize)]
+pub struct StocktakeClose {
+    #[serde(default)]
+    pub closed_by: Option<i32>,
+    #[serde(default)]
+    pub uncounted_as_zero: bool,
+}
+
+/// The variance report of a stocktake: what was found against what was expected, in units and value.
+///
+/// # Fields
+/// - `stocktake_id`, `store_id`, `status`, `opened_at`, `closed_at`: As on the `Stocktake`.
+/// - `lines`: Every line of the stocktake, counted or not.
+/// - `uncounted`: The IDs of the products that were expected but not counted.
+/// - `net_variance_quantity`: The total variance of the counted lines in units.
+/// - `net_variance_value`: The total variance of the counted lines in cents.
+/// - `shortage_value`: The value of the goods missing, in cents, as a positive number.
+/// - `surplus_value`: The value of the goods found beyond what was expected, in cents.
+#[derive(Debug, Serialize)]
+pub struct StocktakeVariance {
+    pub stocktake_id: i64,
+    pub store_id: i32,
+    pub status: StocktakeStatus,
+    pub opened_at: NaiveDateTime,
+    pub closed_at: Option<NaiveDateTime>,
+    pub lines: Vec<StocktakeLine>,
+    pub uncounted: Vec<i32>,
+    pub net_variance_quantity: i64,
+    pub net_variance_value: i64,
+    pub shortage_value: i64,
+    pub surplus_value: i64,
+}
@@ -7,6 +7,8 @@ use sqlx::FromRow;
 /// - `store_id`: Unique identifier for the store. This ID is typically assigned by the database upon insertion.
 /// - `name`: The name of the store.
 /// - `location`: The location or address of the store.
+/// - `invoice_prefix`: The code that starts the store's invoice numbers, e.g. `NRB` for `NRB-2026-000123`. Stores without one
+///   use `S` followed by their `store_id`.
 ///
 /// # Examples
 ///
@@ -17,6 +19,7 @@ use sqlx::FromRow;
 ///     store_id: 1,
 ///     name: "My Store".into(),
 ///     location: "123 Main St".into(),
+///     invoice_prefix: Some("MST".into()),
 /// };
 ///
 /// assert_eq!(store.name, "My Store");
@@ -30,6 +33,8 @@ pub struct Store {
     pub store_id: i64,
     pub name: String,
     pub location: String,
+    #[serde(default)]
+    pub invoice_prefix: Option<String>,
 }
 
 /// Constructs a new `Store` instance.
@@ -56,10 +61,12 @@ pub struct Store {
 ///
 /// # Notes
 /// - The `store_id` field is initialized to `0` and should be updated with a value assigned by the database after insertion.
+#[allow(dead_code)]
 pub fn build_store(name: String, location: String) -> Store {
     Store {
         store_id: 0,
         name,
         location,
+        invoice_prefix: None,
     }
 }
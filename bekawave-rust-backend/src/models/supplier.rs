@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Represents a supplier the business buys stock from.
+///
+/// # Fields
+/// - `supplier_id`: Unique identifier for the supplier, assigned by the database.
+/// - `name`: The name of the supplier. Unique.
+/// - `phone_no`: Optional phone number of the supplier.
+/// - `email`: Optional email address of the supplier.
+/// - `location`: Optional location or address of the supplier.
+///
+/// # Examples
+///
+/// ```json
+/// { "name": "Kapa Oil Refineries", "phone_no": "0722000000", "email": "orders@kapa.example", "location": "Nairobi" }
+/// ```
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct Supplier {
+    #[serde(default)]
+    pub supplier_id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub phone_no: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub location: Option<String>,
+}
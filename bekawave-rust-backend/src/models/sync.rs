@@ -0,0 +1,178 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use crate::models::{Customer, Debt, Product, Sales, Stock};
+
+/// A batch of work done on a rep's device while it was offline, uploaded in one go.
+///
+/// # Fields
+/// - `device_id`: Identifies the device the batch comes from. Recorded with every operation applied.
+/// - `sync_token`: The `sync_token` returned by the device's last sync, or `None` on its first sync. Decides which
+///   server-side changes are sent back.
+/// - `operations`: The operations to apply, in the order they were done on the device.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "device_id": "rep-tablet-07",
+///   "sync_token": 48211,
+///   "operations": [
+///     { "operation_id": "0b6f1d2e-3c4a-4e7b-9a51-7c2d9e0f4a11", "kind": "create_customer",
+///       "customer": { "customer_id": 0, "name": "Mama Njeri", "phone_no": "0722000111", "location": "Gilgil" } },
+///     { "operation_id": "5d0c6b7a-8e9f-4a1b-b2c3-d4e5f6a7b8c9", "kind": "create_sales",
+///       "customer_operation_id": "0b6f1d2e-3c4a-4e7b-9a51-7c2d9e0f4a11",
+///       "sales": { "sales_id": 0, "customer_id": 0, "sales_pair_id": null, "sales_rep_id": 4, "store_id": 2,
+///                  "total_price": 4640, "sales_time": "2024-08-01T10:15:00", "payment_mode": "credit",
+///                  "lines": [{ "product_id": 3, "product_quantity": 2 }] } },
+///     { "operation_id": "9e8d7c6b-5a49-4837-a261-50f4e3d2c1b0", "kind": "pay_debt",
+///       "debt_id": 17, "amount": 1500, "paid_at": "2024-08-01T11:00:00" },
+///     { "operation_id": "1a2b3c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d", "kind": "count_stock",
+///       "store_id": 2, "product_id": 3, "expected_quantity": 38, "counted_quantity": 36 }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct SyncRequest {
+    pub device_id: String,
+    #[serde(default)]
+    pub sync_token: Option<i64>,
+    #[serde(default)]
+    pub operations: Vec<SyncOperation>,
+}
+
+/// One operation done on a device while offline.
+///
+/// # Fields
+/// - `operation_id`: A UUID generated by the device for the operation. An operation is applied at most once, so a batch
+///   can safely be uploaded again after a lost response.
+/// - `action`: What the operation does. Its `kind` is written next to `operation_id`.
+#[derive(Debug, Deserialize)]
+pub struct SyncOperation {
+    pub operation_id: String,
+    #[serde(flatten)]
+    pub action: SyncAction,
+}
+
+/// What an offline operation does.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SyncAction {
+    /// Registers a new customer. If a customer with the same phone number already exists, the operation conflicts and
+    /// resolves to that customer instead.
+    CreateCustomer {
+        customer: Customer,
+    },
+    /// Records a sale. `customer_operation_id` points at a `create_customer` operation, in this batch or an earlier one,
+    /// for sales to customers registered offline; it takes the place of `sales.customer_id`. The sale conflicts if the
+    /// store no longer holds enough stock, if the server prices it differently from the device, or if that customer's
+    /// ID does not fit a sale's 32-bit `customer_id`.
+    CreateSales {
+        sales: Sales,
+        #[serde(default)]
+        customer_operation_id: Option<String>,
+    },
+    /// Records money collected against a debt. Paying less than what is owed leaves the rest as an unpaid debt. The
+    /// operation conflicts if the debt has been paid or reduced below `amount` since the device last synced.
+    PayDebt {
+        debt_id: i64,
+        amount: i64,
+        paid_at: NaiveDateTime,
+    },
+    /// Sets a store's stock of a product to what the rep counted. `expected_quantity` is what the device believed the
+    /// store held at the time of the count; if the server holds a different quantity by the time the operation is
+    /// applied, stock moved in the meantime and the operation conflicts.
+    CountStock {
+        store_id: i32,
+        product_id: i32,
+        expected_quantity: i32,
+        counted_quantity: i32,
+    },
+}
+
+impl SyncAction {
+    /// The `kind` of the action as written in requests.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SyncAction::CreateCustomer { .. } => "create_customer",
+            SyncAction::CreateSales { .. } => "create_sales",
+            SyncAction::PayDebt { .. } => "pay_debt",
+            SyncAction::CountStock { .. } => "count_stock",
+        }
+    }
+}
+
+/// What became of an offline operation.
+///
+/// Stored in the database as the `sync_status` enum type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "sync_status", rename_all = "snake_case")]
+pub enum SyncStatus {
+    /// The operation was applied.
+    Applied,
+    /// The server's data changed since the device last synced and the operation was not applied as sent.
+    Conflict,
+    /// The operation is invalid and was not applied. It can be sent again once fixed, e.g. after the operation it
+    /// depends on has been synced.
+    Rejected,
+}
+
+/// The result of one offline operation.
+///
+/// # Fields
+/// - `operation_id`: The ID of the operation.
+/// - `status`: What became of the operation.
+/// - `entity_id`: The ID of the record the operation resolved to: the customer, sale, debt or stock record. Set for
+///   applied operations, and for conflicting `create_customer` operations, which resolve to the existing customer.
+/// - `message`: Why the operation conflicted or was rejected.
+///
+/// # Notes
+/// - Applied and conflicting operations are final: uploading one again gives back the result it was given the first
+///   time without applying it again.
+#[derive(Debug, Serialize)]
+pub struct SyncOperationResult {
+    pub operation_id: String,
+    pub status: SyncStatus,
+    pub entity_id: Option<i64>,
+    pub message: Option<String>,
+}
+
+/// A record deleted on the server since the device last synced.
+///
+/// # Fields
+/// - `entity`: The kind of record: `customer`, `product`, `stock_record` or `debt`.
+/// - `entity_id`: The ID of the deleted record.
+#[derive(Debug, Serialize)]
+pub struct SyncDeletion {
+    pub entity: String,
+    pub entity_id: i64,
+}
+
+/// The server-side records created, changed or deleted since a device last synced.
+///
+/// On a device's first sync every record is included.
+#[derive(Debug, Default, Serialize)]
+pub struct SyncChanges {
+    pub customers: Vec<Customer>,
+    pub products: Vec<Product>,
+    pub stock: Vec<Stock>,
+    pub debts: Vec<Debt>,
+    pub deleted: Vec<SyncDeletion>,
+}
+
+/// The answer to a `SyncRequest`.
+///
+/// # Fields
+/// - `results`: One result per operation, in the order the operations were sent.
+/// - `changes`: The server-side changes since the request's `sync_token`, including those made by the batch itself.
+/// - `sync_token`: The token to send with the device's next sync.
+///
+/// # Notes
+/// - A record changed around the time of a sync can be sent again on the next one. Devices should apply changes as
+///   upserts.
+#[derive(Debug, Serialize)]
+pub struct SyncResponse {
+    pub results: Vec<SyncOperationResult>,
+    pub changes: SyncChanges,
+    pub sync_token: i64,
+}
@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// How a product is treated for VAT.
+///
+/// Stored in the database as the `tax_category` enum type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "tax_category", rename_all = "snake_case")]
+pub enum TaxCategory {
+    /// Charged VAT at the standard rate.
+    #[default]
+    Standard,
+    /// Taxable, but at a rate of zero.
+    ZeroRated,
+    /// Outside VAT altogether.
+    Exempt,
+}
+
+/// The VAT rules sales are priced under.
+///
+/// # Fields
+/// - `standard_rate`: The standard VAT rate in basis points, e.g. `1600` for 16%.
+/// - `prices_include_tax`: Whether product, tier and customer prices already include VAT. When `false`, VAT is added
+///   on top of them.
+#[derive(Debug, Clone, Copy)]
+pub struct TaxSettings {
+    pub standard_rate: i32,
+    pub prices_include_tax: bool,
+}
+
+impl Default for TaxSettings {
+    fn default() -> Self {
+        TaxSettings { standard_rate: 1600, prices_include_tax: true }
+    }
+}
+
+impl TaxSettings {
+    /// The VAT rate in basis points of a tax category.
+    pub fn rate(&self, category: TaxCategory) -> i32 {
+        match category {
+            TaxCategory::Standard => self.standard_rate,
+            TaxCategory::ZeroRated | TaxCategory::Exempt => 0,
+        }
+    }
+
+    /// Splits the amount charged for a line, after discounts, into its net amount and VAT.
+    ///
+    /// With tax-inclusive prices `amount` is the gross and the VAT is taken out of it; otherwise `amount` is the net and
+    /// the VAT is added to it. VAT is rounded half up to the cent.
+    ///
+    /// # Returns
+    ///
+    /// The net amount and the VAT in cents. The line's gross is their sum.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crate::models::{TaxCategory, TaxSettings};
+    ///
+    /// let inclusive = TaxSettings { standard_rate: 1600, prices_include_tax: true };
+    /// assert_eq!(inclusive.split(TaxCategory::Standard, 11600), (10000, 1600));
+    ///
+    /// let exclusive = TaxSettings { standard_rate: 1600, prices_include_tax: false };
+    /// assert_eq!(exclusive.split(TaxCategory::Standard, 10000), (10000, 1600));
+    /// ```
+    pub fn split(&self, category: TaxCategory, amount: i64) -> (i64, i64) {
+        let rate = self.rate(category) as i128;
+        let amount_wide = amount as i128;
+        if self.prices_include_tax {
+            let tax = round_half_up(amount_wide * rate, 10000 + rate);
+            (amount - tax, tax)
+        } else {
+            (amount, round_half_up(amount_wide * rate, 10000))
+        }
+    }
+}
+
+/// Divides `numerator` by a positive `denominator`, rounding halves away from zero.
+fn round_half_up(numerator: i128, denominator: i128) -> i64 {
+    let quotient = (numerator.abs() * 2 + denominator) / (denominator * 2);
+    (quotient * numerator.signum()) as i64
+}
+
+/// The VAT on the sales of a period for one tax category and rate.
+///
+/// # Fields
+/// - `tax_category`: The tax category of the goods sold.
+/// - `tax_rate`: The VAT rate in basis points the goods were sold at.
+/// - `line_count`: The number of sales lines.
+/// - `net_total`: The net amount of the sales in cents.
+/// - `tax_total`: The VAT charged on the sales in cents.
+/// - `gross_total`: The amount charged for the sales in cents, VAT included.
+/// - `returned_net`: The net amount in cents refunded for goods returned in the period.
+/// - `returned_tax`: The VAT in cents refunded for goods returned in the period.
+/// - `returned_gross`: The amount in cents refunded for goods returned in the period, VAT included.
+///
+/// # Notes
+/// - Voided sales are left out. Returns are counted in the period they were made in, whenever the goods were sold.
+/// - Lines recorded before VAT was tracked carry a `tax_rate` of `0` and no VAT.
+#[derive(Debug, Serialize, FromRow)]
+pub struct TaxSummary {
+    pub tax_category: TaxCategory,
+    pub tax_rate: i32,
+    pub line_count: i64,
+    pub net_total: i64,
+    pub tax_total: i64,
+    pub gross_total: i64,
+    pub returned_net: i64,
+    pub returned_tax: i64,
+    pub returned_gross: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TaxCategory, TaxSettings};
+
+    const INCLUSIVE: TaxSettings = TaxSettings { standard_rate: 1600, prices_include_tax: true };
+    const EXCLUSIVE: TaxSettings = TaxSettings { standard_rate: 1600, prices_include_tax: false };
+
+    #[test]
+    fn takes_vat_out_of_inclusive_prices() {
+        assert_eq!(INCLUSIVE.split(TaxCategory::Standard, 11600), (10000, 1600));
+        // 100 cents carry 13.79 cents of VAT.
+        assert_eq!(INCLUSIVE.split(TaxCategory::Standard, 100), (86, 14));
+    }
+
+    #[test]
+    fn adds_vat_to_exclusive_prices() {
+        assert_eq!(EXCLUSIVE.split(TaxCategory::Standard, 10000), (10000, 1600));
+        assert_eq!(EXCLUSIVE.split(TaxCategory::Standard, 28), (28, 4));
+        assert_eq!(EXCLUSIVE.split(TaxCategory::Standard, 47), (47, 8));
+    }
+
+    #[test]
+    fn rounds_half_cents_away_from_zero() {
+        let settings = TaxSettings { standard_rate: 1000, prices_include_tax: false };
+        assert_eq!(settings.split(TaxCategory::Standard, 5), (5, 1));
+        assert_eq!(settings.split(TaxCategory::Standard, -5), (-5, -1));
+        assert_eq!(settings.split(TaxCategory::Standard, 4), (4, 0));
+    }
+
+    #[test]
+    fn charges_no_vat_on_zero_rated_and_exempt_goods() {
+        for settings in [INCLUSIVE, EXCLUSIVE] {
+            assert_eq!(settings.split(TaxCategory::ZeroRated, 11600), (11600, 0));
+            assert_eq!(settings.split(TaxCategory::Exempt, 11600), (11600, 0));
+        }
+    }
+}
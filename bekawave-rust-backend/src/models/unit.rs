@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// The base unit products are given when none is set.
+pub const DEFAULT_BASE_UNIT: &str = "piece";
+
+/// Returns `DEFAULT_BASE_UNIT` as an owned string, for fields that default to it.
+pub fn default_base_unit() -> String {
+    DEFAULT_BASE_UNIT.to_string()
+}
+
+/// A unit other than its base unit that a product is bought, stored or sold in, such as a carton or crate.
+///
+/// Quantities given in such a unit are turned into base units as soon as they are received, so stock, sales and the
+/// stock ledger only ever hold base units. Changing a factor therefore changes nothing already recorded.
+///
+/// # Fields
+/// - `product_id`: The ID of the product.
+/// - `unit`: The name of the unit. Unique per product, ignoring case, and different from the product's base unit.
+/// - `factor`: How many base units one of this unit holds, e.g. `12` for a carton of twelve pieces.
+///
+/// # Examples
+///
+/// ```json
+/// { "product_id": 3, "unit": "carton", "factor": 24 }
+/// ```
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct ProductUnit {
+    pub product_id: i32,
+    pub unit: String,
+    pub factor: i32,
+}
+
+/// The units a product can be counted in.
+///
+/// # Fields
+/// - `product_id`: The ID of the product.
+/// - `base_unit`: The unit stock and sales of the product are kept in, and its price is for.
+/// - `units`: The product's other units, by name.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "product_id": 3,
+///   "base_unit": "piece",
+///   "units": [{ "product_id": 3, "unit": "carton", "factor": 24 }, { "product_id": 3, "unit": "crate", "factor": 6 }]
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct ProductUnits {
+    pub product_id: i32,
+    pub base_unit: String,
+    pub units: Vec<ProductUnit>,
+}
+
+/// A quantity of base units shown in another unit, as whole units and the base units left over.
+///
+/// # Fields
+/// - `unit`: The unit shown.
+/// - `factor`: How many base units one of `unit` holds.
+/// - `quantity`: The number of whole `unit`s.
+/// - `remainder`: The base units left over. Negative, like `quantity`, for a negative quantity.
+///
+/// # Examples
+///
+/// ```rust
+/// use crate::models::UnitQuantity;
+///
+/// let cartons = UnitQuantity::new("carton", 24, 50);
+/// assert_eq!((cartons.quantity, cartons.remainder), (2, 2));
+/// ```
+#[derive(Debug, Serialize)]
+pub struct UnitQuantity {
+    pub unit: String,
+    pub factor: i32,
+    pub quantity: i64,
+    pub remainder: i64,
+}
+
+impl UnitQuantity {
+    /// Shows `base_quantity` base units in `unit`, which holds `factor` base units.
+    pub fn new(unit: &str, factor: i32, base_quantity: i64) -> Self {
+        let factor_base = i64::from(factor.max(1));
+        UnitQuantity {
+            unit: unit.to_string(),
+            factor,
+            quantity: base_quantity / factor_base,
+            remainder: base_quantity % factor_base,
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::str::FromStr;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// How the cost of goods leaving a store is worked out from what they cost to receive.
+///
+/// Chosen per deployment with the `VALUATION_METHOD` setting, as `fifo` or `weighted_average`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValuationMethod {
+    /// First in, first out: goods leave at the cost of the oldest units still held, so what is left is valued at the
+    /// most recent receipt costs.
+    Fifo,
+    /// Goods leave at the average cost of the units held, which every receipt moves towards its own cost.
+    #[default]
+    WeightedAverage,
+}
+
+impl FromStr for ValuationMethod {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "fifo" => Ok(ValuationMethod::Fifo),
+            "weighted_average" => Ok(ValuationMethod::WeightedAverage),
+            other => Err(format!("unknown valuation method '{}'", other)),
+        }
+    }
+}
+
+/// The value of a store's stock of one product.
+///
+/// # Fields
+/// - `store_id`: The ID of the store.
+/// - `product_id`: The ID of the product.
+/// - `quantity`: The quantity the store held.
+/// - `unit_cost`: The average cost of each unit held, in cents. `0` when the store held none.
+/// - `value`: What the units held cost, in cents. Stock sold before it was received is worth nothing.
+#[derive(Debug, Serialize)]
+pub struct StockValuation {
+    pub store_id: i32,
+    pub product_id: i32,
+    pub quantity: i64,
+    pub unit_cost: i64,
+    pub value: i64,
+}
+
+/// The value of all stock held by a store.
+#[derive(Debug, Serialize)]
+pub struct StoreValuation {
+    pub store_id: i32,
+    pub quantity: i64,
+    pub value: i64,
+}
+
+/// The value of a product held across all stores.
+#[derive(Debug, Serialize)]
+pub struct ProductValuation {
+    pub product_id: i32,
+    pub quantity: i64,
+    pub value: i64,
+}
+
+/// The value of the stock on hand at a point in time, costed from the stock ledger.
+///
+/// # Fields
+/// - `method`: The valuation method the stock was costed with.
+/// - `as_of`: The time the stock was valued at; every movement recorded up to then is taken into account.
+/// - `lines`: The value of each store's stock of each product, by store and then product.
+/// - `stores`: The value of each store's stock.
+/// - `products`: The value of each product across the stores.
+/// - `total_quantity`: The quantity held across all lines.
+/// - `total_value`: The value of all lines, in cents.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "method": "fifo",
+///   "as_of": "2024-08-31T23:59:59.999999999",
+///   "lines": [{ "store_id": 2, "product_id": 3, "quantity": 36, "unit_cost": 1250, "value": 45000 }],
+///   "stores": [{ "store_id": 2, "quantity": 36, "value": 45000 }],
+///   "products": [{ "product_id": 3, "quantity": 36, "value": 45000 }],
+///   "total_quantity": 36,
+///   "total_value": 45000
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct InventoryValuation {
+    pub method: ValuationMethod,
+    pub as_of: NaiveDateTime,
+    pub lines: Vec<StockValuation>,
+    pub stores: Vec<StoreValuation>,
+    pub products: Vec<ProductValuation>,
+    pub total_quantity: i64,
+    pub total_value: i64,
+}
+
+/// The cost of a product a store sold over a period.
+///
+/// # Fields
+/// - `store_id`: The ID of the store.
+/// - `product_id`: The ID of the product.
+/// - `quantity`: The quantity sold, net of sales put back and goods returned.
+/// - `cost`: What the goods sold cost, net of the cost of those put back and returned, in cents.
+#[derive(Debug, Serialize)]
+pub struct CostOfGoodsSoldLine {
+    pub store_id: i32,
+    pub product_id: i32,
+    pub quantity: i64,
+    pub cost: i64,
+}
+
+/// The cost of the goods sold over a period, costed from the stock ledger.
+///
+/// # Fields
+/// - `method`: The valuation method the goods were costed with.
+/// - `from`: The start of the period.
+/// - `to`: The end of the period.
+/// - `lines`: The cost of each product each store sold, by store and then product.
+/// - `total_quantity`: The quantity sold across all lines.
+/// - `total_cost`: The cost of all lines, in cents.
+#[derive(Debug, Serialize)]
+pub struct CostOfGoodsSold {
+    pub method: ValuationMethod,
+    pub from: NaiveDateTime,
+    pub to: NaiveDateTime,
+    pub lines: Vec<CostOfGoodsSoldLine>,
+    pub total_quantity: i64,
+    pub total_cost: i64,
+}
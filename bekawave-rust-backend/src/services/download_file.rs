@@ -1,67 +1,147 @@
-use std::io::Cursor;
-use chrono::{Local, NaiveDateTime};
+use chrono::Local;
 use csv::WriterBuilder;
-use rocket::http::ContentType;
-use rocket::response::Response;
-use crate::services::{CustomerService, ProductService, SalesError, SalesService};
+use rocket::http::{ContentType, Header};
+use crate::models::{Product, QuotationStatus, Sales, SalesLine};
+use crate::services::{CustomerService, ProductService, QuotationError, QuotationService, SalesError, SalesService, StoreService};
+
+/// A generated file, sent to be saved under its name rather than shown.
+#[derive(Debug, Responder)]
+pub struct DownloadedFile {
+    contents: Vec<u8>,
+    content_type: ContentType,
+    disposition: Header<'static>,
+}
 
 pub async fn download_file(
     product_service: &ProductService,
     customer_service: &CustomerService,
     sales_service: &SalesService,
+    store_service: &StoreService,
     format: &str,
-) -> Result<Response<'static>, SalesError> {
+    store_id: Option<i32>,
+) -> Result<DownloadedFile, SalesError> {
     let csv_name = Local::now().date_naive().to_string();
 
-    // Retrieve all sales records
-    let sales_list = sales_service.get_all_sales().await?;
+    // Retrieve all sales records, optionally for a single store
+    let sales_list = sales_service.get_all_sales(store_id).await?;
 
-    let mut sales_with_customer_names = Vec::new();
+    let mut rows = Vec::new();
 
     for sale in sales_list {
         // Retrieve the customer associated with each sale
-        let customer = customer_service.get_customer(sale.customer_id).await.unwrap();
-        let product = product_service.get_product(sale.product_id).await.unwrap();
-
-        // Create a new struct that includes customer name with the sales record
-        let sale_with_name = SalesWithCustomerName {
-            sales_id: sale.sales_id,
-            customer_name: customer.name.clone(),
-            sales_rep_id: sale.sales_rep_id,
-            total_price: sale.total_price,
-            sales_time: sale.sales_time,
-            product_name: product.name.clone(),
-            product_price: product.price,
-            product_quantity: sale.product_quantity,
-        };
-
-        sales_with_customer_names.push(sale_with_name);
+        let customer = customer_service.get_customer(sale.customer_id).await?;
+        let store = store_service.get_store(sale.store_id).await?;
+
+        // Write one row per line item of the order
+        for line in &sale.lines {
+            let product = product_service.get_product(line.product_id).await?;
+            rows.push(sales_row(&sale, line, &store.name, &customer.name, &product));
+        }
     }
 
+    write_file(&format!("sales-{}", csv_name), format, &SALES_HEADERS, rows).ok_or(SalesError::UnsupportedFormat)
+}
+
+/// The columns of the sales download, one row per line item.
+const SALES_HEADERS: [&str; 10] = [
+    "Sales ID",
+    "Store",
+    "Customer Name",
+    "Net Price",
+    "Tax",
+    "Gross Price",
+    "Sales Time",
+    "Product name",
+    "Product Price",
+    "Product Quantity",
+];
+
+/// Builds the row of the sales download for one line item of `sale`, in the order of `SALES_HEADERS`.
+fn sales_row(sale: &Sales, line: &SalesLine, store_name: &str, customer_name: &str, product: &Product) -> Vec<String> {
+    vec![
+        sale.sales_id.to_string(),
+        store_name.to_string(),
+        customer_name.to_string(),
+        line.net_price.to_string(),
+        line.tax_amount.to_string(),
+        line.total_price.to_string(),
+        sale.sales_time.to_string(),
+        product.name.clone(),
+        product.price.to_string(),
+        line.product_quantity.to_string(),
+    ]
+}
+
+pub async fn download_quotations(
+    product_service: &ProductService,
+    customer_service: &CustomerService,
+    quotation_service: &QuotationService,
+    store_service: &StoreService,
+    format: &str,
+    status: Option<QuotationStatus>,
+) -> Result<DownloadedFile, QuotationError> {
+    let csv_name = Local::now().date_naive().to_string();
+
+    // Retrieve all quotations, optionally with a single status
+    let quotations = quotation_service.get_all_quotations(status, None).await?;
+
+    let mut rows = Vec::new();
+
+    for quotation in quotations {
+        let customer = customer_service.get_customer(quotation.customer_id).await?;
+        let store = store_service.get_store(quotation.store_id).await?;
+
+        // Write one row per line of the quotation
+        for line in &quotation.lines {
+            let product = product_service.get_product(line.product_id).await?;
+
+            rows.push(vec![
+                quotation.quotation_id.to_string(),
+                format!("{:?}", quotation.status),
+                store.name.clone(),
+                customer.name.clone(),
+                line.net_price.to_string(),
+                line.tax_amount.to_string(),
+                line.total_price.to_string(),
+                quotation.quoted_at.to_string(),
+                quotation.valid_until.to_string(),
+                product.name.clone(),
+                line.unit_price.to_string(),
+                line.product_quantity.to_string(),
+            ]);
+        }
+    }
+
+    let headers = [
+        "Quotation ID",
+        "Status",
+        "Store",
+        "Customer Name",
+        "Net Price",
+        "Tax",
+        "Gross Price",
+        "Quoted At",
+        "Valid Until",
+        "Product name",
+        "Unit Price",
+        "Product Quantity",
+    ];
+
+    write_file(&format!("quotations-{}", csv_name), format, &headers, rows).ok_or(QuotationError::UnsupportedFormat)
+}
+
+/// Writes `rows` under `headers` as a `csv` or `xlsx` file called `name`, returning `None` for any other format.
+fn write_file(name: &str, format: &str, headers: &[&str], rows: Vec<Vec<String>>) -> Option<DownloadedFile> {
     let (content_type, file_contents) = match format {
         "csv" => {
             let mut wtr = WriterBuilder::new().from_writer(vec![]);
 
             // Write CSV headers
-            wtr.write_record(&[
-                "Customer Name",
-                "Total Price",
-                "Sales Time",
-                "Product name",
-                "Product Price",
-                "Product Quantity",
-            ]).unwrap();
+            wtr.write_record(headers).unwrap();
 
             // Write CSV data rows
-            for sale in sales_with_customer_names {
-                wtr.write_record(&[
-                    sale.customer_name,
-                    sale.total_price.to_string(),
-                    sale.sales_time.to_string(),
-                    sale.product_name,
-                    sale.product_price.to_string(),
-                    sale.product_quantity.to_string(),
-                ]).unwrap();
+            for row in rows {
+                wtr.write_record(&row).unwrap();
             }
 
             let file_contents = wtr.into_inner().unwrap();
@@ -72,28 +152,16 @@ pub async fn download_file(
             let sheet = book.get_sheet_mut(&0).unwrap();
 
             // Write XLSX headers
-            let headers = [
-                "Customer Name",
-                "Total Price",
-                "Sales Time",
-                "Product name",
-                "Product Price",
-                "Product Quantity",
-            ];
-
             for (col_num, header) in headers.iter().enumerate() {
                 sheet.get_cell_mut((1, col_num as u32 + 1)).set_value(header.to_string());
             }
 
             // Write XLSX data rows
-            for (row_num, sale) in sales_with_customer_names.iter().enumerate() {
+            for (row_num, row) in rows.into_iter().enumerate() {
                 let row_num = row_num as u32 + 2;  // Start from row 2
-                sheet.get_cell_mut((row_num, 1)).set_value(&sale.customer_name);
-                sheet.get_cell_mut((row_num, 2)).set_value(sale.total_price.to_string());
-                sheet.get_cell_mut((row_num, 3)).set_value(&sale.sales_time.to_string());
-                sheet.get_cell_mut((row_num, 4)).set_value(&sale.product_name);
-                sheet.get_cell_mut((row_num, 5)).set_value(sale.product_price.to_string());
-                sheet.get_cell_mut((row_num, 6)).set_value(sale.product_quantity.to_string());
+                for (col_num, value) in row.into_iter().enumerate() {
+                    sheet.get_cell_mut((row_num, col_num as u32 + 1)).set_value(value);
+                }
             }
 
             let mut writer = vec![];
@@ -101,26 +169,51 @@ pub async fn download_file(
             (ContentType::new("application", "vnd.openxmlformats-officedocument.spreadsheetml.sheet"), writer)
         }
         _ => {
-            return Err(SalesError::UnsupportedFormat);
+            return None;
         }
     };
 
-    let response = Response::build()
-        .header(content_type)
-        .sized_body(file_contents.len(), Cursor::new(file_contents))
-        .finalize();
-
-    Ok(response)
+    Some(DownloadedFile {
+        contents: file_contents,
+        content_type,
+        disposition: Header::new("Content-Disposition", format!("attachment; filename=\"{}.{}\"", name, format)),
+    })
 }
 
-// Example struct to hold sales data along with customer names
-struct SalesWithCustomerName {
-    sales_id: i64,
-    customer_name: String,
-    sales_rep_id: Option<i32>,
-    total_price: i32,
-    sales_time: NaiveDateTime,
-    product_name: String,
-    product_price: i64,
-    product_quantity: i32,
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rocket::http::ContentType;
+    use crate::models::{build_product, build_sales, build_sales_line, PaymentMode};
+    use super::{sales_row, write_file, SALES_HEADERS};
+
+    #[test]
+    fn sales_rows_name_the_store_of_the_sale() {
+        let sales_time = NaiveDate::from_ymd_opt(2024, 8, 3).unwrap().and_hms_opt(9, 30, 0).unwrap();
+        let mut line = build_sales_line(4, 3, 0, None);
+        (line.net_price, line.tax_amount, line.total_price) = (1034, 166, 1200);
+        let mut sale = build_sales(2, None, None, 7, 1200, sales_time, PaymentMode::Cash, 1200, vec![]);
+        sale.sales_id = 15;
+
+        let row = sales_row(&sale, &line, "Westlands", "Jane", &build_product("Soap".to_string(), 400));
+
+        assert_eq!(row.len(), SALES_HEADERS.len());
+        assert_eq!(row, ["15", "Westlands", "Jane", "1034", "166", "1200", "2024-08-03 09:30:00", "Soap", "400", "3"]);
+    }
+
+    #[test]
+    fn csv_files_are_sent_as_attachments() {
+        let rows = vec![vec!["1".to_string(), "Soap, large".to_string()]];
+        let file = write_file("sales-2024-08-03", "csv", &["Sales ID", "Product name"], rows).unwrap();
+
+        assert_eq!(file.contents, b"Sales ID,Product name\n1,\"Soap, large\"\n".to_vec());
+        assert_eq!(file.content_type, ContentType::CSV);
+        assert_eq!(file.disposition.value(), "attachment; filename=\"sales-2024-08-03.csv\"");
+    }
+
+    #[test]
+    fn other_formats_are_not_written() {
+        assert!(write_file("sales-2024-08-03", "pdf", &["Sales ID"], vec![]).is_none());
+        assert!(write_file("sales-2024-08-03", "CSV", &["Sales ID"], vec![]).is_none());
+    }
 }
@@ -0,0 +1,352 @@
+use lopdf::content::{Content, Operation};
+use lopdf::{dictionary, Document, Object, StringFormat, Stream};
+use crate::models::{Invoice, PaymentMode};
+use crate::services::InvoiceError;
+
+/// A4 page size in points.
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+const LINE_HEIGHT: f32 = 16.0;
+
+/// Fonts registered on every page. Amounts use the monospaced font so they can be right-aligned.
+const REGULAR: &str = "F1";
+const BOLD: &str = "F2";
+const MONO: &str = "F3";
+
+/// Left edge of the product column and right edges of the amount columns, which are right-aligned.
+const COL_PRODUCT: f32 = MARGIN;
+const COL_QUANTITY_END: f32 = 245.0;
+const COL_UNIT_PRICE_END: f32 = 305.0;
+const COL_DISCOUNT_END: f32 = 365.0;
+const COL_NET_END: f32 = 425.0;
+const COL_TAX_END: f32 = 480.0;
+const COL_TOTAL_END: f32 = PAGE_WIDTH - MARGIN;
+
+/// Product names longer than this are cut short so they do not run into the quantity column.
+const PRODUCT_NAME_MAX_CHARS: usize = 30;
+
+/// Renders an invoice as a PDF document.
+///
+/// The document uses the standard PDF fonts only, so it needs no font files and renders the same on every viewer. Long
+/// invoices continue on further pages, with the column headings repeated.
+///
+/// # Arguments
+///
+/// * `invoice` - The invoice to render.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - On success, returns the bytes of the PDF document.
+/// * `Err(InvoiceError)` - If the document could not be encoded.
+pub fn render_invoice_pdf(invoice: &Invoice) -> Result<Vec<u8>, InvoiceError> {
+    let mut writer = PageWriter::new();
+
+    writer.text(BOLD, 18.0, MARGIN, &invoice.business.name);
+    writer.next_line(6.0);
+    for detail in [&invoice.business.address, &invoice.business.phone] {
+        if !detail.is_empty() {
+            writer.text(REGULAR, 10.0, MARGIN, detail);
+            writer.next_line(0.0);
+        }
+    }
+    writer.next_line(8.0);
+
+    writer.text(BOLD, 14.0, MARGIN, if invoice.voided { "INVOICE (VOID)" } else { "INVOICE" });
+    writer.next_line(4.0);
+    let invoice_number = invoice.invoice_number.clone().unwrap_or_else(|| invoice.sales_id.to_string());
+    writer.text(REGULAR, 10.0, MARGIN, &format!("Invoice no.: {}", invoice_number));
+    writer.next_line(0.0);
+    writer.text(REGULAR, 10.0, MARGIN, &format!("Date: {}", invoice.sales_time.format("%Y-%m-%d %H:%M")));
+    writer.next_line(0.0);
+    if let Some(store_name) = &invoice.store_name {
+        writer.text(REGULAR, 10.0, MARGIN, &format!("Store: {}", store_name));
+        writer.next_line(0.0);
+    }
+    if let Some(sold_by) = &invoice.sold_by {
+        writer.text(REGULAR, 10.0, MARGIN, &format!("Sold by: {}", sold_by));
+        writer.next_line(0.0);
+    }
+    writer.next_line(6.0);
+
+    writer.text(BOLD, 10.0, MARGIN, "Bill to");
+    writer.next_line(0.0);
+    writer.text(REGULAR, 10.0, MARGIN, &invoice.customer_name);
+    writer.next_line(0.0);
+    writer.text(REGULAR, 10.0, MARGIN, &invoice.customer_phone);
+    writer.next_line(10.0);
+
+    writer.line_item_header();
+    for line in &invoice.lines {
+        if writer.is_page_full() {
+            writer.new_page();
+            writer.line_item_header();
+        }
+        writer.text(REGULAR, 10.0, COL_PRODUCT, &shorten(&line.product_name, PRODUCT_NAME_MAX_CHARS));
+        writer.amount(10.0, COL_QUANTITY_END, &line.quantity.to_string());
+        writer.amount(10.0, COL_UNIT_PRICE_END, &format_cents(line.unit_price));
+        if line.discount != 0 {
+            writer.amount(10.0, COL_DISCOUNT_END, &format!("-{}", format_cents(line.discount)));
+        }
+        writer.amount(10.0, COL_NET_END, &format_cents(line.net_price));
+        writer.amount(10.0, COL_TAX_END, &format_cents(line.tax_amount));
+        writer.amount(10.0, COL_TOTAL_END, &format_cents(line.total_price));
+        writer.next_line(0.0);
+    }
+    writer.rule();
+    writer.next_line(4.0);
+
+    let payment_mode = match invoice.payment_mode {
+        PaymentMode::Cash => "Cash",
+        PaymentMode::Credit => "Credit",
+        PaymentMode::Partial => "Partial payment",
+    };
+    let mut totals = vec![
+        ("Net", format_cents(invoice.net_total)),
+        ("VAT", format_cents(invoice.tax_total)),
+        ("Total", format_cents(invoice.total_price)),
+        ("Payment", payment_mode.to_string()),
+        ("Paid", format_cents(invoice.amount_paid)),
+    ];
+    if invoice.balance_due > 0 {
+        totals.push(("Balance due", format_cents(invoice.balance_due)));
+    }
+    if invoice.customer_balance > 0 {
+        totals.push(("Total outstanding balance", format_cents(invoice.customer_balance)));
+    }
+    for (label, value) in totals {
+        if writer.is_page_full() {
+            writer.new_page();
+        }
+        writer.text(BOLD, 10.0, COL_DISCOUNT_END - 60.0, label);
+        writer.amount(10.0, COL_TOTAL_END, &value);
+        writer.next_line(0.0);
+    }
+
+    writer.finish()
+}
+
+/// Formats an amount in cents with two decimals, e.g. `-1234` as `-12.34`.
+pub fn format_cents(amount: i64) -> String {
+    let sign = if amount < 0 { "-" } else { "" };
+    let amount = amount.unsigned_abs();
+    format!("{}{}.{:02}", sign, amount / 100, amount % 100)
+}
+
+/// Lays text out top to bottom over as many pages as needed.
+struct PageWriter {
+    pages: Vec<Vec<Operation>>,
+    y: f32,
+}
+
+impl PageWriter {
+    fn new() -> Self {
+        PageWriter {
+            pages: vec![Vec::new()],
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    fn is_page_full(&self) -> bool {
+        self.y < MARGIN + LINE_HEIGHT
+    }
+
+    fn new_page(&mut self) {
+        self.pages.push(Vec::new());
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    fn next_line(&mut self, extra_space: f32) {
+        self.y -= LINE_HEIGHT + extra_space;
+    }
+
+    fn operations(&mut self) -> &mut Vec<Operation> {
+        self.pages.last_mut().expect("a page is always open")
+    }
+
+    /// Writes `text` with its left edge at `x` on the current line.
+    fn text(&mut self, font: &str, size: f32, x: f32, text: &str) {
+        let y = self.y;
+        self.operations().extend([
+            Operation::new("BT", vec![]),
+            Operation::new("Tf", vec![font.into(), size.into()]),
+            Operation::new("Td", vec![x.into(), y.into()]),
+            Operation::new("Tj", vec![Object::String(encode_text(text), StringFormat::Literal)]),
+            Operation::new("ET", vec![]),
+        ]);
+    }
+
+    /// Writes `text` in the monospaced font with its right edge at `right`.
+    fn amount(&mut self, size: f32, right: f32, text: &str) {
+        // Every Courier glyph is 600 units of a 1000 unit em wide.
+        let width = text.chars().count() as f32 * size * 0.6;
+        self.text(MONO, size, right - width, text);
+    }
+
+    /// Draws a horizontal rule just above the current line.
+    fn rule(&mut self) {
+        let y = self.y + LINE_HEIGHT - 4.0;
+        self.operations().extend([
+            Operation::new("w", vec![0.5.into()]),
+            Operation::new("m", vec![MARGIN.into(), y.into()]),
+            Operation::new("l", vec![(PAGE_WIDTH - MARGIN).into(), y.into()]),
+            Operation::new("S", vec![]),
+        ]);
+    }
+
+    fn line_item_header(&mut self) {
+        self.text(BOLD, 10.0, COL_PRODUCT, "Product");
+        self.text(BOLD, 10.0, COL_QUANTITY_END - 18.0, "Qty");
+        self.text(BOLD, 10.0, COL_UNIT_PRICE_END - 47.0, "Unit price");
+        self.text(BOLD, 10.0, COL_DISCOUNT_END - 42.0, "Discount");
+        self.text(BOLD, 10.0, COL_NET_END - 19.0, "Net");
+        self.text(BOLD, 10.0, COL_TAX_END - 22.0, "VAT");
+        self.text(BOLD, 10.0, COL_TOTAL_END - 25.0, "Total");
+        self.next_line(0.0);
+        self.rule();
+    }
+
+    /// Assembles the pages into a PDF document.
+    fn finish(self) -> Result<Vec<u8>, InvoiceError> {
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+
+        let font = |base_font: &str| dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => base_font.to_string(),
+            "Encoding" => "WinAnsiEncoding",
+        };
+        let regular_id = doc.add_object(font("Helvetica"));
+        let bold_id = doc.add_object(font("Helvetica-Bold"));
+        let mono_id = doc.add_object(font("Courier"));
+        let resources_id = doc.add_object(dictionary! {
+            "Font" => dictionary! {
+                REGULAR => regular_id,
+                BOLD => bold_id,
+                MONO => mono_id,
+            },
+        });
+
+        let mut kids = Vec::with_capacity(self.pages.len());
+        for operations in self.pages {
+            let content = Content { operations };
+            let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode()?));
+            let page_id = doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => pages_id,
+                "Contents" => content_id,
+            });
+            kids.push(Object::Reference(page_id));
+        }
+
+        let page_count = kids.len() as i64;
+        doc.objects.insert(pages_id, Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => kids,
+            "Count" => page_count,
+            "Resources" => resources_id,
+            "MediaBox" => vec![0.into(), 0.into(), PAGE_WIDTH.into(), PAGE_HEIGHT.into()],
+        }));
+
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.compress();
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+/// Cuts `text` down to `max_chars` characters, ending it with `...` when it was longer.
+fn shorten(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(max_chars.saturating_sub(3)).collect();
+    format!("{}...", kept)
+}
+
+/// Encodes text for the standard fonts, which use the WinAnsi (Latin-1 based) encoding.
+///
+/// Characters outside Latin-1 are printed as `?`.
+fn encode_text(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| u8::try_from(u32::from(c)).unwrap_or(b'?'))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use lopdf::Document;
+    use crate::models::{BusinessDetails, Invoice, InvoiceLine, PaymentMode};
+    use super::{encode_text, format_cents, render_invoice_pdf, shorten};
+
+    fn invoice(line_count: usize) -> Invoice {
+        let lines = (0..line_count)
+            .map(|i| InvoiceLine {
+                product_name: format!("Product {}", i),
+                quantity: 2,
+                unit_price: 580,
+                discount: 0,
+                net_price: 1000,
+                tax_amount: 160,
+                total_price: 1160,
+            })
+            .collect();
+        Invoice {
+            business: BusinessDetails { name: "Bekawave".to_string(), address: String::new(), phone: "0700 000000".to_string() },
+            sales_id: 12,
+            invoice_number: Some("S1-2024-000012".to_string()),
+            sales_time: NaiveDate::from_ymd_opt(2024, 8, 3).unwrap().and_hms_opt(9, 30, 0).unwrap(),
+            store_name: Some("Main".to_string()),
+            customer_name: "Jane".to_string(),
+            customer_phone: "0711 111111".to_string(),
+            sold_by: None,
+            lines,
+            net_total: 1000 * line_count as i64,
+            tax_total: 160 * line_count as i64,
+            total_price: 1160 * line_count as i64,
+            payment_mode: PaymentMode::Partial,
+            amount_paid: 500,
+            balance_due: 1160 * line_count as i64 - 500,
+            customer_balance: 1160 * line_count as i64 - 500,
+            voided: false,
+        }
+    }
+
+    #[test]
+    fn cents_are_shown_with_two_decimals() {
+        assert_eq!(format_cents(123456), "1234.56");
+        assert_eq!(format_cents(5), "0.05");
+        assert_eq!(format_cents(-1234), "-12.34");
+        assert_eq!(format_cents(i64::MIN), "-92233720368547758.08");
+    }
+
+    #[test]
+    fn long_product_names_are_shortened() {
+        assert_eq!(shorten("Soap", 6), "Soap");
+        assert_eq!(shorten("Washing powder", 10), "Washing...");
+        assert_eq!(shorten("Crème brûlée", 8), "Crème...");
+    }
+
+    #[test]
+    fn text_outside_latin_1_is_replaced() {
+        assert_eq!(encode_text("Crème"), b"Cr\xe8me".to_vec());
+        assert_eq!(encode_text("5€"), b"5?".to_vec());
+    }
+
+    #[test]
+    fn long_invoices_continue_on_further_pages() {
+        let short = Document::load_mem(&render_invoice_pdf(&invoice(3)).unwrap()).unwrap();
+        let long = Document::load_mem(&render_invoice_pdf(&invoice(120)).unwrap()).unwrap();
+
+        assert_eq!(short.get_pages().len(), 1);
+        assert!(long.get_pages().len() > 2);
+    }
+}
@@ -1,4 +1,12 @@
 mod stock_service;
+mod stock_transfer_service;
+mod reorder_service;
+mod valuation_service;
+mod stocktake_service;
+mod supplier_service;
+mod purchase_order_service;
+mod stock_lot_service;
+mod unit_service;
 mod store_service;
 mod sales_rep_service;
 mod product_service;
@@ -6,14 +14,40 @@ mod customer_service;
 mod sales_pair_service;
 mod debt_service;
 mod sales_service;
+mod sales_return_service;
+mod invoice_service;
+mod invoice_pdf;
+mod price_list_service;
+mod promotion_service;
+mod tax_service;
+mod quotation_service;
+mod idempotency_service;
+mod sync_service;
 mod download_file;
 
 pub use stock_service::*;
+pub use stock_transfer_service::*;
+pub use reorder_service::*;
+pub use valuation_service::*;
+pub use stocktake_service::*;
+pub use supplier_service::*;
+pub use purchase_order_service::*;
+pub use stock_lot_service::*;
+pub use unit_service::*;
 pub use store_service::*;
 pub use sales_rep_service::*;
 pub use product_service::*;
 pub use customer_service::*;
 pub use debt_service::*;
 pub use sales_service::*;
+pub use sales_return_service::*;
+pub use invoice_service::*;
+pub use invoice_pdf::*;
+pub use price_list_service::*;
+pub use promotion_service::*;
+pub use tax_service::*;
+pub use quotation_service::*;
+pub use idempotency_service::*;
+pub use sync_service::*;
 pub use sales_pair_service::*;
 pub use download_file::*;
\ No newline at end of file